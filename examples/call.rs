@@ -2,12 +2,18 @@ use twilio::{Client, OutboundCall};
 
 #[tokio::main]
 async fn main() {
-    let to = "<to-number>";
-    let from = "<from-number>";
+    let to = "<to-number>"
+        .parse()
+        .expect("to-number should be in E.164 format");
+    let from = "<from-number>"
+        .parse()
+        .expect("from-number should be in E.164 format");
     let url = "https://demo.twilio.com/welcome/voice/";
-    let app_id = "my_app_id";
+    let account_sid = "<account-sid>"
+        .parse()
+        .expect("account-sid should be an AC SID");
     let auth_token = "my_auth_token";
-    let client = Client::new(app_id, auth_token);
+    let client = Client::new(account_sid, auth_token);
     let call = OutboundCall::new(from, to, url);
     match client.make_call(call).await {
         Ok(m) => println!("{:?}", m),
@@ -2,12 +2,18 @@ use twilio::{Client, OutboundMessage};
 
 #[tokio::main]
 async fn main() {
-    let to = "<to-number>";
-    let from = "<from-number>";
+    let to = "<to-number>"
+        .parse()
+        .expect("to-number should be in E.164 format");
+    let from = "<from-number>"
+        .parse()
+        .expect("from-number should be in E.164 format");
     let body = "Hello, World! ";
-    let app_id = "<app-id>";
+    let account_sid = "<account-sid>"
+        .parse()
+        .expect("account-sid should be an AC SID");
     let auth_token = "<auth-token>";
-    let client = Client::new(app_id, auth_token);
+    let client = Client::new(account_sid, auth_token);
     let msg = OutboundMessage::new(from, to, body);
     match client.send_message(msg).await {
         Ok(m) => println!("{:?}", m),
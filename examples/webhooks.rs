@@ -11,9 +11,11 @@ use tokio::task::JoinSet;
 use twilio::twiml::{Say, Twiml, Voice};
 
 async fn handle(req: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
-    let app_id = "<app-id>";
+    let account_sid = "<account-sid>"
+        .parse()
+        .expect("account-sid should be an AC SID");
     let auth_token = "<auth-token>";
-    let client = twilio::Client::new(app_id, auth_token);
+    let client = twilio::Client::new(account_sid, auth_token);
 
     let cloned_uri = req.uri().clone();
     println!("Got a request for: {}", cloned_uri);
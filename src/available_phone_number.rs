@@ -0,0 +1,280 @@
+use crate::incoming_phone_number::{
+    IncomingPhoneNumber, PhoneNumberCapabilities, PhoneNumberSettings,
+};
+use crate::{de, Client, ListResource, Page, PhoneNumber, PhoneNumberKind, TwilioError, POST};
+use isocountry::CountryCode;
+use serde::Deserialize;
+
+/// The kind of phone number to search for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvailableNumberType {
+    /// A geographic number tied to an area code.
+    Local,
+    TollFree,
+    Mobile,
+}
+
+impl AsRef<str> for AvailableNumberType {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Local => "Local",
+            Self::TollFree => "TollFree",
+            Self::Mobile => "Mobile",
+        }
+    }
+}
+
+/// A phone number that can be bought with [`Client::purchase_phone_number`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct AvailablePhoneNumber {
+    pub phone_number: PhoneNumber,
+    pub friendly_name: Option<String>,
+    pub iso_country: CountryCode,
+    pub region: Option<String>,
+    pub locality: Option<String>,
+    pub postal_code: Option<String>,
+    pub rate_center: Option<String>,
+    pub lata: Option<String>,
+    #[serde(default, deserialize_with = "de::from_str_opt")]
+    pub latitude: Option<f64>,
+    #[serde(default, deserialize_with = "de::from_str_opt")]
+    pub longitude: Option<f64>,
+    /// Which kind of address, if any, regulations require before the number can be bought:
+    /// `none`, `any`, `local` or `foreign`.
+    pub address_requirements: Option<String>,
+    #[serde(default)]
+    pub beta: bool,
+    #[serde(default)]
+    pub capabilities: PhoneNumberCapabilities,
+}
+
+impl ListResource for AvailablePhoneNumber {
+    const LIST_KEY: &'static str = "available_phone_numbers";
+}
+
+/// Narrows down the numbers returned by [`Client::search_phone_numbers`].
+///
+/// The `near_*` and `in_*` filters only apply to numbers in the US and Canada.
+#[derive(Debug, Clone, Default)]
+pub struct AvailableNumberFilter<'a> {
+    pub area_code: Option<u16>,
+    /// A pattern the number must contain, of digits, letters standing for their keypad
+    /// digits, and `*` matching any single digit, e.g. `510555****` or `STORM`.
+    pub contains: Option<&'a str>,
+    pub sms_enabled: Option<bool>,
+    pub mms_enabled: Option<bool>,
+    pub voice_enabled: Option<bool>,
+    pub fax_enabled: Option<bool>,
+    /// Leave out numbers that require an address to be bought.
+    pub exclude_all_address_required: Option<bool>,
+    pub exclude_local_address_required: Option<bool>,
+    pub exclude_foreign_address_required: Option<bool>,
+    /// Include numbers new to Twilio.
+    pub beta: Option<bool>,
+    /// Only numbers geographically close to this number.
+    pub near_number: Option<&'a PhoneNumber>,
+    /// Only numbers geographically close to this latitude and longitude.
+    pub near_lat_long: Option<(f64, f64)>,
+    /// Search radius in miles for `near_number` and `near_lat_long`, up to 500.
+    pub distance: Option<u32>,
+    pub in_postal_code: Option<&'a str>,
+    /// Two letter state or province abbreviation.
+    pub in_region: Option<&'a str>,
+    /// Requires `in_lata` to be set too.
+    pub in_rate_center: Option<&'a str>,
+    pub in_lata: Option<&'a str>,
+    pub in_locality: Option<&'a str>,
+    /// Number of results, up to 50.  Twilio returns at most one page of results.
+    pub page_size: Option<u32>,
+}
+
+impl AvailableNumberFilter<'_> {
+    fn params(&self) -> Result<Vec<(&'static str, String)>, TwilioError> {
+        if self.distance.is_some() && self.near_number.is_none() && self.near_lat_long.is_none() {
+            return Err(TwilioError::ValidationError(
+                "Distance requires NearNumber or NearLatLong",
+            ));
+        }
+        if self.distance.is_some_and(|d| d > 500) {
+            return Err(TwilioError::ValidationError(
+                "Distance cannot exceed 500 miles",
+            ));
+        }
+        if self.in_rate_center.is_some() && self.in_lata.is_none() {
+            return Err(TwilioError::ValidationError("InRateCenter requires InLata"));
+        }
+
+        let mut params = Vec::new();
+        if let Some(a) = self.area_code {
+            params.push(("AreaCode", a.to_string()));
+        }
+        let strings = [
+            ("Contains", self.contains),
+            ("InPostalCode", self.in_postal_code),
+            ("InRegion", self.in_region),
+            ("InRateCenter", self.in_rate_center),
+            ("InLata", self.in_lata),
+            ("InLocality", self.in_locality),
+        ];
+        params.extend(
+            strings
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        let bools = [
+            ("SmsEnabled", self.sms_enabled),
+            ("MmsEnabled", self.mms_enabled),
+            ("VoiceEnabled", self.voice_enabled),
+            ("FaxEnabled", self.fax_enabled),
+            (
+                "ExcludeAllAddressRequired",
+                self.exclude_all_address_required,
+            ),
+            (
+                "ExcludeLocalAddressRequired",
+                self.exclude_local_address_required,
+            ),
+            (
+                "ExcludeForeignAddressRequired",
+                self.exclude_foreign_address_required,
+            ),
+            ("Beta", self.beta),
+        ];
+        params.extend(
+            bools
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        if let Some(n) = self.near_number {
+            params.push(("NearNumber", n.to_string()));
+        }
+        if let Some((lat, long)) = self.near_lat_long {
+            params.push(("NearLatLong", format!("{},{}", lat, long)));
+        }
+        if let Some(d) = self.distance {
+            params.push(("Distance", d.to_string()));
+        }
+        match self.page_size {
+            Some(size @ 1..=50) => params.push(("PageSize", size.to_string())),
+            Some(_) => {
+                return Err(TwilioError::ValidationError(
+                    "PageSize must be between 1 and 50",
+                ))
+            }
+            None => {}
+        }
+        Ok(params)
+    }
+}
+
+impl Client {
+    /// Searches for phone numbers that can be bought in `country`.
+    pub async fn search_phone_numbers(
+        &self,
+        country: CountryCode,
+        kind: AvailableNumberType,
+        filter: &AvailableNumberFilter<'_>,
+    ) -> Result<Vec<AvailablePhoneNumber>, TwilioError> {
+        let endpoint = format!(
+            "AvailablePhoneNumbers/{}/{}",
+            country.alpha2(),
+            kind.as_ref()
+        );
+        let page: Page<AvailablePhoneNumber> =
+            self.first_page(&endpoint, &filter.params()?).await?;
+        Ok(page.items)
+    }
+
+    /// Buys `phone_number`, as found in [`AvailablePhoneNumber::phone_number`], and configures
+    /// it with `settings`.  Numbers that require an address need `settings.address_sid`, and
+    /// those subject to regulations need `settings.bundle_sid`.
+    pub async fn purchase_phone_number(
+        &self,
+        phone_number: &PhoneNumber,
+        settings: &PhoneNumberSettings<'_>,
+    ) -> Result<IncomingPhoneNumber, TwilioError> {
+        settings.validate()?;
+        if phone_number.kind() != PhoneNumberKind::E164 {
+            return Err(TwilioError::ValidationError(
+                "PhoneNumber must be in E.164 format",
+            ));
+        }
+        let mut params = vec![("PhoneNumber", phone_number.to_string())];
+        settings.push_params(&mut params);
+        self.send_request(POST, "IncomingPhoneNumbers", &params)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_params() {
+        let filter = AvailableNumberFilter {
+            area_code: Some(510),
+            contains: Some("555****"),
+            sms_enabled: Some(true),
+            near_lat_long: Some((37.840699, -122.461853)),
+            distance: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.params().unwrap(),
+            [
+                ("AreaCode", "510".to_string()),
+                ("Contains", "555****".to_string()),
+                ("SmsEnabled", "true".to_string()),
+                ("NearLatLong", "37.840699,-122.461853".to_string()),
+                ("Distance", "50".to_string()),
+            ]
+        );
+
+        let filter = AvailableNumberFilter {
+            distance: Some(50),
+            ..Default::default()
+        };
+        assert!(filter.params().is_err());
+        let filter = AvailableNumberFilter {
+            in_rate_center: Some("OKLD0349T"),
+            ..Default::default()
+        };
+        assert!(filter.params().is_err());
+    }
+
+    #[test]
+    fn test_deserialize_available_number() {
+        let s = r#"{
+            "available_phone_numbers": [{
+                "address_requirements": "none",
+                "beta": false,
+                "capabilities": {"mms": true, "sms": false, "voice": true},
+                "friendly_name": "(808) 925-1571",
+                "iso_country": "US",
+                "lata": "834",
+                "latitude": "19.720000",
+                "locality": "Hilo",
+                "longitude": "-155.090000",
+                "phone_number": "+18089251571",
+                "postal_code": "96720",
+                "rate_center": "HILO",
+                "region": "HI"
+            }],
+            "end": 1,
+            "first_page_uri": "/2010-04-01/Accounts/ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/AvailablePhoneNumbers/US/Local.json?PageSize=50&Page=0",
+            "next_page_uri": null,
+            "page": 0,
+            "page_size": 50,
+            "previous_page_uri": null,
+            "start": 0,
+            "uri": "/2010-04-01/Accounts/ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/AvailablePhoneNumbers/US/Local.json?PageSize=1"
+        }"#;
+        let page: Page<AvailablePhoneNumber> = serde_json::from_str(s).unwrap();
+        let n = &page.items[0];
+        assert_eq!(n.iso_country, CountryCode::USA);
+        assert_eq!(n.latitude, Some(19.72));
+        assert!(n.capabilities.mms && n.capabilities.voice && !n.capabilities.sms);
+    }
+}
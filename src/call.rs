@@ -1,21 +1,536 @@
-use crate::{Client, FromMap, TwilioError, POST};
-use serde::Deserialize;
+use crate::twiml::{Method, Twiml};
+use crate::{
+    de, AccountSid, ApplicationSid, CallSid, Client, FromMap, ListResource, Page, PhoneNumber,
+    TrunkSid, TwilioError, GET, NO_PARAMS, POST,
+};
+use bitflags::bitflags;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use core::fmt;
+use core::str::FromStr;
+use futures_core::Stream;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 
+/// Maximum length of inline TwiML, in characters.
+const MAX_TWIML_CHARS: usize = 4000;
+
+/// A call to place through [`Client::make_call`].
+///
+/// [`OutboundCall::new`] places a call driven by a TwiML URL; use [`OutboundCall::builder`] for
+/// everything else.  Exactly one of `url`, `twiml` or `application_sid` must be set.
+#[derive(Debug, Clone)]
 pub struct OutboundCall<'a> {
-    pub from: &'a str,
-    pub to: &'a str,
-    pub url: &'a str,
+    pub from: PhoneNumber,
+    pub to: PhoneNumber,
+    pub url: Option<&'a str>,
+    pub twiml: Option<&'a Twiml>,
+    pub application_sid: Option<ApplicationSid>,
+    pub method: Option<Method>,
+    pub fallback_url: Option<&'a str>,
+    pub fallback_method: Option<Method>,
+    pub status_callback: Option<&'a str>,
+    pub status_callback_method: Option<Method>,
+    pub status_callback_event: StatusCallbackEvent,
+    /// Seconds to let the call ring before giving up, up to 600.
+    pub timeout: Option<u32>,
+    /// DTMF tones to play once the call connects: digits, `#`, `*`, and `w`/`W` pauses.
+    pub send_digits: Option<&'a str>,
+    pub record: Option<CallRecording<'a>>,
+    pub machine_detection: Option<MachineDetection<'a>>,
+    /// The caller ID shown when `to` is a SIP address or Voice SDK client.
+    pub caller_id: Option<PhoneNumber>,
+    pub sip_auth_username: Option<&'a str>,
+    pub sip_auth_password: Option<&'a str>,
+    /// Maximum length of the call in seconds, up to 24 hours.
+    pub time_limit: Option<u32>,
 }
 
 impl<'a> OutboundCall<'a> {
-    pub fn new(from: &'a str, to: &'a str, url: &'a str) -> OutboundCall<'a> {
-        OutboundCall { from, to, url }
+    pub fn new(from: PhoneNumber, to: PhoneNumber, url: &'a str) -> OutboundCall<'a> {
+        Self::builder(from, to).url(url).call
+    }
+
+    pub fn builder(from: PhoneNumber, to: PhoneNumber) -> OutboundCallBuilder<'a> {
+        OutboundCallBuilder {
+            call: OutboundCall {
+                from,
+                to,
+                url: None,
+                twiml: None,
+                application_sid: None,
+                method: None,
+                fallback_url: None,
+                fallback_method: None,
+                status_callback: None,
+                status_callback_method: None,
+                status_callback_event: StatusCallbackEvent::default(),
+                timeout: None,
+                send_digits: None,
+                record: None,
+                machine_detection: None,
+                caller_id: None,
+                sip_auth_username: None,
+                sip_auth_password: None,
+                time_limit: None,
+            },
+        }
+    }
+
+    /// Checks the call against the parameter rules Twilio documents for the Calls resource.
+    pub fn validate(&self) -> Result<(), TwilioError> {
+        let instructions = [
+            self.url.is_some(),
+            self.twiml.is_some(),
+            self.application_sid.is_some(),
+        ];
+        if instructions.iter().filter(|i| **i).count() != 1 {
+            return Err(TwilioError::ValidationError(
+                "exactly one of Url, Twiml or ApplicationSid is required",
+            ));
+        }
+        if self
+            .twiml
+            .is_some_and(|t| t.as_twiml().chars().count() > MAX_TWIML_CHARS)
+        {
+            return Err(TwilioError::ValidationError(
+                "Twiml cannot exceed 4000 characters",
+            ));
+        }
+        if !self.status_callback_event.is_empty() && self.status_callback.is_none() {
+            return Err(TwilioError::ValidationError(
+                "StatusCallbackEvent requires StatusCallback",
+            ));
+        }
+        if self.timeout.is_some_and(|t| t == 0 || t > 600) {
+            return Err(TwilioError::ValidationError(
+                "Timeout must be between 1 and 600 seconds",
+            ));
+        }
+        if self.send_digits.is_some_and(|d| {
+            !d.chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '#' | '*' | 'w' | 'W'))
+        }) {
+            return Err(TwilioError::ValidationError(
+                "SendDigits may only contain digits, '#', '*', 'w' and 'W'",
+            ));
+        }
+        if self.time_limit.is_some_and(|t| t == 0 || t > 86400) {
+            return Err(TwilioError::ValidationError(
+                "TimeLimit must be between 1 and 86400 seconds",
+            ));
+        }
+        if !self.to.can_receive() {
+            return Err(TwilioError::ValidationError(
+                "To cannot be a short code or alphanumeric sender ID",
+            ));
+        }
+        if self.sip_auth_username.is_some() != self.sip_auth_password.is_some() {
+            return Err(TwilioError::ValidationError(
+                "SipAuthUsername and SipAuthPassword must be set together",
+            ));
+        }
+        if let Some(ref amd) = self.machine_detection {
+            amd.validate()?;
+        }
+        Ok(())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![("To", self.to.to_string()), ("From", self.from.to_string())];
+        let strings = [
+            ("Url", self.url),
+            ("FallbackUrl", self.fallback_url),
+            ("StatusCallback", self.status_callback),
+            ("SendDigits", self.send_digits),
+            ("SipAuthUsername", self.sip_auth_username),
+            ("SipAuthPassword", self.sip_auth_password),
+        ];
+        params.extend(
+            strings
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        if let Some(sid) = self.application_sid {
+            params.push(("ApplicationSid", sid.to_string()));
+        }
+        if let Some(ref c) = self.caller_id {
+            params.push(("CallerId", c.to_string()));
+        }
+        if let Some(t) = self.twiml {
+            params.push(("Twiml", t.as_twiml()));
+        }
+        let methods = [
+            ("Method", self.method),
+            ("FallbackMethod", self.fallback_method),
+            ("StatusCallbackMethod", self.status_callback_method),
+        ];
+        params.extend(
+            methods
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.as_ref().to_string()))),
+        );
+        params.extend(
+            self.status_callback_event
+                .names()
+                .map(|e| ("StatusCallbackEvent", e.to_string())),
+        );
+        if let Some(t) = self.timeout {
+            params.push(("Timeout", t.to_string()));
+        }
+        if let Some(t) = self.time_limit {
+            params.push(("TimeLimit", t.to_string()));
+        }
+        if let Some(ref r) = self.record {
+            params.push(("Record", "true".to_string()));
+            r.push_params(&mut params);
+        }
+        if let Some(ref amd) = self.machine_detection {
+            amd.push_params(&mut params);
+        }
+        params
     }
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "lowercase")]
+/// Builds an [`OutboundCall`], validating it on [`build`](Self::build).
+#[derive(Debug, Clone)]
+pub struct OutboundCallBuilder<'a> {
+    call: OutboundCall<'a>,
+}
+
+impl<'a> OutboundCallBuilder<'a> {
+    /// Fetches the TwiML for the call from `url` once it connects.
+    pub fn url(mut self, url: &'a str) -> Self {
+        self.call.url = Some(url);
+        self
+    }
+
+    /// Runs the given TwiML once the call connects, instead of fetching it from a URL.
+    pub fn twiml(mut self, twiml: &'a Twiml) -> Self {
+        self.call.twiml = Some(twiml);
+        self
+    }
+
+    pub fn application_sid(mut self, sid: ApplicationSid) -> Self {
+        self.call.application_sid = Some(sid);
+        self
+    }
+
+    pub fn method(mut self, method: Method) -> Self {
+        self.call.method = Some(method);
+        self
+    }
+
+    pub fn fallback_url(mut self, url: &'a str, method: Method) -> Self {
+        self.call.fallback_url = Some(url);
+        self.call.fallback_method = Some(method);
+        self
+    }
+
+    /// Requests status callbacks to `url` for the given call progress events.  With no events,
+    /// Twilio only calls back once the call completes.
+    pub fn status_callback(
+        mut self,
+        url: &'a str,
+        method: Method,
+        events: StatusCallbackEvent,
+    ) -> Self {
+        self.call.status_callback = Some(url);
+        self.call.status_callback_method = Some(method);
+        self.call.status_callback_event = events;
+        self
+    }
+
+    pub fn timeout(mut self, seconds: u32) -> Self {
+        self.call.timeout = Some(seconds);
+        self
+    }
+
+    pub fn send_digits(mut self, digits: &'a str) -> Self {
+        self.call.send_digits = Some(digits);
+        self
+    }
+
+    pub fn record(mut self, recording: CallRecording<'a>) -> Self {
+        self.call.record = Some(recording);
+        self
+    }
+
+    pub fn machine_detection(mut self, machine_detection: MachineDetection<'a>) -> Self {
+        self.call.machine_detection = Some(machine_detection);
+        self
+    }
+
+    pub fn caller_id(mut self, caller_id: PhoneNumber) -> Self {
+        self.call.caller_id = Some(caller_id);
+        self
+    }
+
+    pub fn sip_auth(mut self, username: &'a str, password: &'a str) -> Self {
+        self.call.sip_auth_username = Some(username);
+        self.call.sip_auth_password = Some(password);
+        self
+    }
+
+    pub fn time_limit(mut self, seconds: u32) -> Self {
+        self.call.time_limit = Some(seconds);
+        self
+    }
+
+    pub fn build(self) -> Result<OutboundCall<'a>, TwilioError> {
+        self.call.validate()?;
+        Ok(self.call)
+    }
+}
+
+bitflags! {
+    /// Call progress events to receive status callbacks for.
+    #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+    pub struct StatusCallbackEvent: u8 {
+        const Initiated = 0x01;
+        const Ringing = 0x02;
+        const Answered = 0x04;
+        const Completed = 0x08;
+    }
+}
+
+impl StatusCallbackEvent {
+    pub(crate) fn names(self) -> impl Iterator<Item = &'static str> {
+        IntoIterator::into_iter([
+            (Self::Initiated, "initiated"),
+            (Self::Ringing, "ringing"),
+            (Self::Answered, "answered"),
+            (Self::Completed, "completed"),
+        ])
+        .filter(move |(flag, _)| self.contains(*flag))
+        .map(|(_, name)| name)
+    }
+}
+
+bitflags! {
+    /// Recording progress events to receive recording status callbacks for.
+    #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+    pub struct RecordingStatusCallbackEvent: u8 {
+        const InProgress = 0x01;
+        const Completed = 0x02;
+        const Absent = 0x04;
+    }
+}
+
+impl RecordingStatusCallbackEvent {
+    pub(crate) fn names(self) -> impl Iterator<Item = &'static str> {
+        IntoIterator::into_iter([
+            (Self::InProgress, "in-progress"),
+            (Self::Completed, "completed"),
+            (Self::Absent, "absent"),
+        ])
+        .filter(move |(flag, _)| self.contains(*flag))
+        .map(|(_, name)| name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingChannels {
+    Mono,
+    Dual,
+}
+
+impl AsRef<str> for RecordingChannels {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Mono => "mono",
+            Self::Dual => "dual",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingTrack {
+    Inbound,
+    Outbound,
+    Both,
+}
+
+impl AsRef<str> for RecordingTrack {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Inbound => "inbound",
+            Self::Outbound => "outbound",
+            Self::Both => "both",
+        }
+    }
+}
+
+/// How a call is recorded.  The default records in Twilio's default configuration.
+#[derive(Debug, Clone, Default)]
+pub struct CallRecording<'a> {
+    pub channels: Option<RecordingChannels>,
+    pub status_callback: Option<&'a str>,
+    pub status_callback_method: Option<Method>,
+    pub status_callback_event: RecordingStatusCallbackEvent,
+    /// Whether to trim leading and trailing silence.
+    pub trim: Option<bool>,
+    pub track: Option<RecordingTrack>,
+}
+
+impl CallRecording<'_> {
+    /// Appends the recording parameters, which are named the same whether a recording is
+    /// requested when creating a call or started on a live one.
+    pub(crate) fn push_params(&self, params: &mut Vec<(&'static str, String)>) {
+        if let Some(c) = self.channels {
+            params.push(("RecordingChannels", c.as_ref().to_string()));
+        }
+        if let Some(c) = self.status_callback {
+            params.push(("RecordingStatusCallback", c.to_string()));
+        }
+        if let Some(m) = self.status_callback_method {
+            params.push(("RecordingStatusCallbackMethod", m.as_ref().to_string()));
+        }
+        let events = self.status_callback_event.names().collect::<Vec<_>>();
+        if !events.is_empty() {
+            params.push(("RecordingStatusCallbackEvent", events.join(" ")));
+        }
+        if let Some(t) = self.trim {
+            let trim = if t { "trim-silence" } else { "do-not-trim" };
+            params.push(("Trim", trim.to_string()));
+        }
+        if let Some(t) = self.track {
+            params.push(("RecordingTrack", t.as_ref().to_string()));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MachineDetectionMode {
+    /// Return as soon as a human or machine is detected.
+    Enable,
+    /// Wait for the end of a machine greeting before returning, e.g. to leave a voicemail.
+    DetectMessageEnd,
+}
+
+impl AsRef<str> for MachineDetectionMode {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Enable => "Enable",
+            Self::DetectMessageEnd => "DetectMessageEnd",
+        }
+    }
+}
+
+/// Answering machine detection (AMD) settings.  Unset tuning parameters use Twilio's defaults.
+#[derive(Debug, Clone)]
+pub struct MachineDetection<'a> {
+    pub mode: MachineDetectionMode,
+    /// Seconds to spend detecting, from 3 to 59.
+    pub timeout: Option<u32>,
+    /// Milliseconds of speech after which the answerer is considered a machine, from 1000 to
+    /// 6000.
+    pub speech_threshold: Option<u32>,
+    /// Milliseconds of silence that end a speech activity, from 500 to 5000.
+    pub speech_end_threshold: Option<u32>,
+    /// Milliseconds of initial silence after which the result is `unknown`, from 2000 to
+    /// 10000.
+    pub silence_timeout: Option<u32>,
+    /// Connects the call immediately and reports the detection result to
+    /// `async_amd_status_callback` instead of holding the call until detection completes.
+    pub async_amd: bool,
+    pub async_amd_status_callback: Option<&'a str>,
+    pub async_amd_status_callback_method: Option<Method>,
+}
+
+impl<'a> MachineDetection<'a> {
+    pub fn new(mode: MachineDetectionMode) -> Self {
+        MachineDetection {
+            mode,
+            timeout: None,
+            speech_threshold: None,
+            speech_end_threshold: None,
+            silence_timeout: None,
+            async_amd: false,
+            async_amd_status_callback: None,
+            async_amd_status_callback_method: None,
+        }
+    }
+
+    /// Switches to asynchronous detection, reporting the result to `callback`.
+    pub fn asynchronous(mut self, callback: &'a str, method: Method) -> Self {
+        self.async_amd = true;
+        self.async_amd_status_callback = Some(callback);
+        self.async_amd_status_callback_method = Some(method);
+        self
+    }
+
+    fn validate(&self) -> Result<(), TwilioError> {
+        let ranges = [
+            (
+                self.timeout,
+                3..=59,
+                "MachineDetectionTimeout must be between 3 and 59",
+            ),
+            (
+                self.speech_threshold,
+                1000..=6000,
+                "MachineDetectionSpeechThreshold must be between 1000 and 6000",
+            ),
+            (
+                self.speech_end_threshold,
+                500..=5000,
+                "MachineDetectionSpeechEndThreshold must be between 500 and 5000",
+            ),
+            (
+                self.silence_timeout,
+                2000..=10000,
+                "MachineDetectionSilenceTimeout must be between 2000 and 10000",
+            ),
+        ];
+        for (value, range, msg) in ranges {
+            if value.is_some_and(|v| !range.contains(&v)) {
+                return Err(TwilioError::ValidationError(msg));
+            }
+        }
+        if self.async_amd_status_callback.is_some() && !self.async_amd {
+            return Err(TwilioError::ValidationError(
+                "AsyncAmdStatusCallback requires AsyncAmd",
+            ));
+        }
+        Ok(())
+    }
+
+    fn push_params(&self, params: &mut Vec<(&'static str, String)>) {
+        params.push(("MachineDetection", self.mode.as_ref().to_string()));
+        let numbers = [
+            ("MachineDetectionTimeout", self.timeout),
+            ("MachineDetectionSpeechThreshold", self.speech_threshold),
+            (
+                "MachineDetectionSpeechEndThreshold",
+                self.speech_end_threshold,
+            ),
+            ("MachineDetectionSilenceTimeout", self.silence_timeout),
+        ];
+        params.extend(
+            numbers
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        if self.async_amd {
+            params.push(("AsyncAmd", "true".to_string()));
+        }
+        if let Some(c) = self.async_amd_status_callback {
+            params.push(("AsyncAmdStatusCallback", c.to_string()));
+        }
+        if let Some(m) = self.async_amd_status_callback_method {
+            params.push(("AsyncAmdStatusCallbackMethod", m.as_ref().to_string()));
+        }
+    }
+}
+
+/// The status of a call.  Statuses this crate does not know about yet are preserved in
+/// `Unknown` rather than failing to parse.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CallStatus {
     Queued,
     Ringing,
@@ -25,53 +540,654 @@ pub enum CallStatus {
     Failed,
     Busy,
     NoAnswer,
+    Unknown(String),
 }
 
+impl AsRef<str> for CallStatus {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Queued => "queued",
+            Self::Ringing => "ringing",
+            Self::InProgress => "in-progress",
+            Self::Canceled => "canceled",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Busy => "busy",
+            Self::NoAnswer => "no-answer",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
+impl fmt::Display for CallStatus {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl CallStatus {
+    /// Parses a status.  Only input that isn't a non-empty UTF-8 string is rejected; anything
+    /// else that isn't a known status becomes `Unknown`.
+    #[inline]
+    pub fn from_bytes(s: &[u8]) -> Result<Self, InvalidCallStatus> {
+        let this = match s {
+            b"queued" => Self::Queued,
+            b"ringing" => Self::Ringing,
+            b"in-progress" => Self::InProgress,
+            b"canceled" => Self::Canceled,
+            b"completed" => Self::Completed,
+            b"failed" => Self::Failed,
+            b"busy" => Self::Busy,
+            b"no-answer" => Self::NoAnswer,
+            b"" => return Err(InvalidCallStatus(String::new())),
+            _ => match core::str::from_utf8(s) {
+                Ok(s) => Self::Unknown(s.to_string()),
+                Err(_) => return Err(InvalidCallStatus(String::from_utf8_lossy(s).to_string())),
+            },
+        };
+        Ok(this)
+    }
+}
+
+impl FromStr for CallStatus {
+    type Err = InvalidCallStatus;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for CallStatus {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        let s = <Cow<'de, str>>::deserialize(de)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for CallStatus {
+    #[inline]
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.serialize_str(self.as_ref())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid Twilio call status '{0}'")]
+pub struct InvalidCallStatus(String);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CallDirection {
+    Inbound,
+    OutboundApi,
+    OutboundDial,
+    TrunkingTerminating,
+    TrunkingOriginating,
+    /// A direction this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Who answered the call, as determined by answering machine detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnsweredBy {
+    Human,
+    MachineStart,
+    MachineEndBeep,
+    MachineEndSilence,
+    MachineEndOther,
+    Fax,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct CallSubresourceUris {
+    pub notifications: Option<String>,
+    pub recordings: Option<String>,
+    pub events: Option<String>,
+    pub payments: Option<String>,
+    pub streams: Option<String>,
+    pub siprec: Option<String>,
+}
+
+/// A Call resource, as returned by the REST API or received in a webhook.  Webhooks carry
+/// fewer fields than the REST API, so fields that a webhook may omit are optional.
 #[derive(Debug, Deserialize)]
 pub struct Call {
-    pub from: String,
-    pub to: String,
-    pub sid: String,
+    pub account_sid: AccountSid,
+    pub from: PhoneNumber,
+    pub to: PhoneNumber,
+    pub sid: CallSid,
     pub status: CallStatus,
+    pub direction: Option<CallDirection>,
+    /// Length of the call in seconds, once it has ended.
+    #[serde(default, deserialize_with = "de::from_str_opt")]
+    pub duration: Option<u32>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub start_time: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub end_time: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_updated: Option<DateTime<Utc>>,
+    /// The amount billed, as the exact decimal string Twilio reports.  Negative for charges.
+    pub price: Option<String>,
+    /// ISO 4217 currency code of `price`.
+    pub price_unit: Option<String>,
+    pub answered_by: Option<AnsweredBy>,
+    #[serde(default, deserialize_with = "de::empty_as_none")]
+    pub forwarded_from: Option<PhoneNumber>,
+    pub caller_name: Option<String>,
+    pub parent_call_sid: Option<CallSid>,
+    /// Milliseconds the call spent queued before it was initiated.
+    #[serde(default, deserialize_with = "de::from_str_opt")]
+    pub queue_time: Option<u64>,
+    #[serde(default, deserialize_with = "de::empty_as_none")]
+    pub trunk_sid: Option<TrunkSid>,
+    pub api_version: Option<String>,
+    pub subresource_uris: Option<CallSubresourceUris>,
 }
 
 impl Client {
     pub async fn make_call(&self, call: OutboundCall<'_>) -> Result<Call, TwilioError> {
-        let opts = [("To", call.to), ("From", call.from), ("Url", call.url)];
-        self.send_request(POST, "Calls", &opts).await
+        call.validate()?;
+        self.send_request(POST, "Calls", &call.params()).await
+    }
+
+    pub async fn get_call(&self, call_sid: &CallSid) -> Result<Call, TwilioError> {
+        self.send_request(GET, &format!("Calls/{}", call_sid), NO_PARAMS)
+            .await
+    }
+
+    /// Streams every call matching `filter`, most recent first, fetching further pages as the
+    /// stream is consumed.
+    pub fn list_calls(
+        &self,
+        filter: &CallFilter<'_>,
+    ) -> Result<impl Stream<Item = Result<Call, TwilioError>> + '_, TwilioError> {
+        Ok(self.paginate("Calls", &filter.params()?))
+    }
+
+    /// Fetches the first page of calls matching `filter`.  Pass the page's `next_page_uri` to
+    /// [`Client::fetch_page`] to continue.
+    pub async fn list_calls_page(
+        &self,
+        filter: &CallFilter<'_>,
+    ) -> Result<Page<Call>, TwilioError> {
+        self.first_page("Calls", &filter.params()?).await
+    }
+
+    /// Modifies a call that is in progress: redirects it to new TwiML, ends it, or changes its
+    /// status callback.
+    pub async fn update_call(
+        &self,
+        call_sid: &CallSid,
+        update: &CallUpdate<'_>,
+    ) -> Result<Call, TwilioError> {
+        update.validate()?;
+        self.send_request(POST, &format!("Calls/{}", call_sid), &update.params())
+            .await
+    }
+}
+
+impl ListResource for Call {
+    const LIST_KEY: &'static str = "calls";
+}
+
+/// Narrows down the calls returned by [`Client::list_calls`].
+#[derive(Debug, Clone, Default)]
+pub struct CallFilter<'a> {
+    pub to: Option<&'a PhoneNumber>,
+    pub from: Option<&'a PhoneNumber>,
+    pub status: Option<CallStatus>,
+    /// Only calls that started on this day (UTC).
+    pub start_time: Option<NaiveDate>,
+    /// Only calls that started on or before this time.
+    pub start_time_before: Option<DateTime<Utc>>,
+    /// Only calls that started on or after this time.
+    pub start_time_after: Option<DateTime<Utc>>,
+    /// Number of calls per page, from 1 to 1000.
+    pub page_size: Option<u32>,
+}
+
+impl CallFilter<'_> {
+    fn params(&self) -> Result<Vec<(&'static str, String)>, TwilioError> {
+        let mut params = Vec::new();
+        if let Some(to) = self.to {
+            params.push(("To", to.to_string()));
+        }
+        if let Some(from) = self.from {
+            params.push(("From", from.to_string()));
+        }
+        if let Some(ref status) = self.status {
+            params.push(("Status", status.as_ref().to_string()));
+        }
+        if let Some(d) = self.start_time {
+            params.push(("StartTime", d.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(t) = self.start_time_before {
+            params.push(("StartTime<", t.to_rfc3339_opts(SecondsFormat::Secs, true)));
+        }
+        if let Some(t) = self.start_time_after {
+            params.push(("StartTime>", t.to_rfc3339_opts(SecondsFormat::Secs, true)));
+        }
+        match self.page_size {
+            Some(size @ 1..=1000) => params.push(("PageSize", size.to_string())),
+            Some(_) => {
+                return Err(TwilioError::ValidationError(
+                    "PageSize must be between 1 and 1000",
+                ))
+            }
+            None => {}
+        }
+        Ok(params)
+    }
+}
+
+/// The status a live call can be moved to in order to end it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndCallStatus {
+    /// Hangs up a call that is in progress.
+    Completed,
+    /// Cancels a call that is queued or ringing.
+    Canceled,
+}
+
+impl AsRef<str> for EndCallStatus {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Completed => "completed",
+            Self::Canceled => "canceled",
+        }
+    }
+}
+
+/// Changes to apply to a live call through [`Client::update_call`].
+#[derive(Debug, Clone, Default)]
+pub struct CallUpdate<'a> {
+    pub url: Option<&'a str>,
+    pub method: Option<Method>,
+    pub twiml: Option<&'a Twiml>,
+    pub status: Option<EndCallStatus>,
+    pub fallback_url: Option<&'a str>,
+    pub fallback_method: Option<Method>,
+    pub status_callback: Option<&'a str>,
+    pub status_callback_method: Option<Method>,
+    pub time_limit: Option<u32>,
+}
+
+impl<'a> CallUpdate<'a> {
+    /// Redirects the call to the TwiML at `url`.
+    pub fn redirect(url: &'a str, method: Method) -> Self {
+        CallUpdate {
+            url: Some(url),
+            method: Some(method),
+            ..Default::default()
+        }
+    }
+
+    /// Replaces whatever the call is doing with the given TwiML.
+    pub fn twiml(twiml: &'a Twiml) -> Self {
+        CallUpdate {
+            twiml: Some(twiml),
+            ..Default::default()
+        }
+    }
+
+    pub fn end(status: EndCallStatus) -> Self {
+        CallUpdate {
+            status: Some(status),
+            ..Default::default()
+        }
+    }
+
+    fn validate(&self) -> Result<(), TwilioError> {
+        if self.url.is_some() && self.twiml.is_some() {
+            return Err(TwilioError::ValidationError(
+                "Url and Twiml cannot both be set",
+            ));
+        }
+        if self.status.is_some() && (self.url.is_some() || self.twiml.is_some()) {
+            return Err(TwilioError::ValidationError(
+                "a call cannot be ended and redirected at the same time",
+            ));
+        }
+        if self
+            .twiml
+            .is_some_and(|t| t.as_twiml().chars().count() > MAX_TWIML_CHARS)
+        {
+            return Err(TwilioError::ValidationError(
+                "Twiml cannot exceed 4000 characters",
+            ));
+        }
+        Ok(())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        let strings = [
+            ("Url", self.url),
+            ("FallbackUrl", self.fallback_url),
+            ("StatusCallback", self.status_callback),
+        ];
+        params.extend(
+            strings
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        if let Some(t) = self.twiml {
+            params.push(("Twiml", t.as_twiml()));
+        }
+        let methods = [
+            ("Method", self.method),
+            ("FallbackMethod", self.fallback_method),
+            ("StatusCallbackMethod", self.status_callback_method),
+        ];
+        params.extend(
+            methods
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.as_ref().to_string()))),
+        );
+        if let Some(s) = self.status {
+            params.push(("Status", s.as_ref().to_string()));
+        }
+        if let Some(t) = self.time_limit {
+            params.push(("TimeLimit", t.to_string()));
+        }
+        params
     }
 }
 
 impl FromMap for Call {
     fn from_map(mut m: BTreeMap<String, String>) -> Result<Box<Call>, TwilioError> {
-        let from = match m.remove("From") {
+        let account_sid = match m.get("AccountSid").and_then(|v| v.parse().ok()) {
             Some(v) => v,
             None => return Err(TwilioError::ParsingError),
         };
-        let to = match m.remove("To") {
-            Some(v) => v,
+        let from = match m.get("From") {
+            Some(v) => PhoneNumber::lenient(v),
+            None => return Err(TwilioError::ParsingError),
+        };
+        let to = match m.get("To") {
+            Some(v) => PhoneNumber::lenient(v),
             None => return Err(TwilioError::ParsingError),
         };
-        let sid = match m.remove("CallSid") {
+        let sid = match m.get("CallSid").and_then(|v| v.parse().ok()) {
             Some(v) => v,
             None => return Err(TwilioError::ParsingError),
         };
-        let stat = match m.get("CallStatus").map(|s| s.as_str()) {
-            Some("queued") => CallStatus::Queued,
-            Some("ringing") => CallStatus::Ringing,
-            Some("in-progress") => CallStatus::InProgress,
-            Some("canceled") => CallStatus::Canceled,
-            Some("completed") => CallStatus::Completed,
-            Some("failed") => CallStatus::Failed,
-            Some("busy") => CallStatus::Busy,
-            Some("no-answer") => CallStatus::NoAnswer,
-            _ => return Err(TwilioError::ParsingError),
+        let status = match m.get("CallStatus") {
+            Some(v) => v.parse().map_err(|_| TwilioError::ParsingError)?,
+            None => return Err(TwilioError::ParsingError),
         };
         Ok(Box::new(Call {
+            account_sid,
             from,
             to,
             sid,
-            status: stat,
+            status,
+            direction: m.get("Direction").and_then(|v| de::from_param(v)),
+            duration: m.get("CallDuration").and_then(|v| v.parse().ok()),
+            start_time: None,
+            end_time: None,
+            date_created: None,
+            date_updated: None,
+            price: None,
+            price_unit: None,
+            answered_by: m.get("AnsweredBy").and_then(|v| de::from_param(v)),
+            forwarded_from: m
+                .get("ForwardedFrom")
+                .filter(|v| !v.is_empty())
+                .map(|v| PhoneNumber::lenient(v)),
+            caller_name: m.remove("CallerName"),
+            parent_call_sid: m.get("ParentCallSid").and_then(|v| v.parse().ok()),
+            queue_time: m.get("QueueTime").and_then(|v| v.parse().ok()),
+            trunk_sid: m.get("TrunkSid").and_then(|v| v.parse().ok()),
+            api_version: m.remove("ApiVersion"),
+            subresource_uris: None,
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::twiml::Say;
+
+    fn number(s: &str) -> PhoneNumber {
+        s.parse().unwrap()
+    }
+
+    fn get<'p>(params: &'p [(&'static str, String)], key: &str) -> Vec<&'p str> {
+        params
+            .iter()
+            .filter(|(k, _)| *k == key)
+            .map(|(_, v)| v.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        let no_instructions =
+            OutboundCall::builder(number("+15005550006"), number("+15005550001")).build();
+        assert!(matches!(
+            no_instructions,
+            Err(TwilioError::ValidationError(_))
+        ));
+
+        let twiml = Twiml::default();
+        let url_and_twiml = OutboundCall::builder(number("+15005550006"), number("+15005550001"))
+            .url("https://example.com/voice")
+            .twiml(&twiml)
+            .build();
+        assert!(matches!(
+            url_and_twiml,
+            Err(TwilioError::ValidationError(_))
+        ));
+
+        let bad_digits = OutboundCall::builder(number("+15005550006"), number("+15005550001"))
+            .url("https://example.com/voice")
+            .send_digits("12a")
+            .build();
+        assert!(matches!(bad_digits, Err(TwilioError::ValidationError(_))));
+
+        let mut amd = MachineDetection::new(MachineDetectionMode::Enable);
+        amd.speech_threshold = Some(100);
+        let bad_amd = OutboundCall::builder(number("+15005550006"), number("+15005550001"))
+            .url("https://example.com/voice")
+            .machine_detection(amd)
+            .build();
+        assert!(matches!(bad_amd, Err(TwilioError::ValidationError(_))));
+
+        // The limit is in characters, so multi-byte text may take up more than 4000 bytes.
+        let say = |txt: String| {
+            let mut twiml = Twiml::default();
+            twiml.add(&Say {
+                txt,
+                voice: crate::twiml::Voice::Alice,
+                language: "fr".to_string(),
+            });
+            twiml
+        };
+        let accented = say("é".repeat(3000));
+        assert!(accented.as_twiml().len() > MAX_TWIML_CHARS);
+        OutboundCall::builder(number("+15005550006"), number("+15005550001"))
+            .twiml(&accented)
+            .build()
+            .unwrap();
+        let too_long = say("e".repeat(4000));
+        let too_long = OutboundCall::builder(number("+15005550006"), number("+15005550001"))
+            .twiml(&too_long)
+            .build();
+        assert!(matches!(too_long, Err(TwilioError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_deserialize_call() {
+        let s = r#"{
+            "account_sid": "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "answered_by": "machine_end_beep",
+            "api_version": "2010-04-01",
+            "caller_name": null,
+            "date_created": "Tue, 31 Aug 2010 20:36:28 +0000",
+            "date_updated": "Tue, 31 Aug 2010 20:36:44 +0000",
+            "direction": "outbound-api",
+            "duration": "15",
+            "end_time": "Tue, 31 Aug 2010 20:36:44 +0000",
+            "forwarded_from": "+141586753093",
+            "from": "+15005550006",
+            "parent_call_sid": null,
+            "price": "-0.03000",
+            "price_unit": "USD",
+            "queue_time": "1000",
+            "sid": "CAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "start_time": "Tue, 31 Aug 2010 20:36:29 +0000",
+            "status": "in-progress",
+            "subresource_uris": {
+                "notifications": "/2010-04-01/Accounts/ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/Calls/CAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/Notifications.json",
+                "recordings": "/2010-04-01/Accounts/ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/Calls/CAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/Recordings.json"
+            },
+            "to": "+14155551212",
+            "trunk_sid": null
+        }"#;
+        let call: Call = serde_json::from_str(s).unwrap();
+        assert_eq!(call.status, CallStatus::InProgress);
+        assert_eq!(call.direction, Some(CallDirection::OutboundApi));
+        assert_eq!(call.answered_by, Some(AnsweredBy::MachineEndBeep));
+        assert_eq!(call.duration, Some(15));
+        assert_eq!(call.queue_time, Some(1000));
+        assert_eq!(
+            call.start_time.unwrap().to_rfc3339(),
+            "2010-08-31T20:36:29+00:00"
+        );
+        assert!(call.subresource_uris.unwrap().recordings.is_some());
+
+        let status: CallStatus = serde_json::from_str("\"no-answer\"").unwrap();
+        assert_eq!(status, CallStatus::NoAnswer);
+        let status: CallStatus = serde_json::from_str("\"teleported\"").unwrap();
+        assert_eq!(status, CallStatus::Unknown("teleported".to_string()));
+        assert!(serde_json::from_str::<CallStatus>("\"\"").is_err());
+        let direction: CallDirection = serde_json::from_str("\"outbound-relay\"").unwrap();
+        assert_eq!(direction, CallDirection::Unknown);
+    }
+
+    #[test]
+    fn test_from_map() {
+        let m = [
+            ("AccountSid", "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+            ("CallSid", "CAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+            ("From", "+15005550006"),
+            ("To", "+14155551212"),
+            ("CallStatus", "no-answer"),
+            ("Direction", "outbound-dial"),
+            ("AnsweredBy", "human"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let call = Call::from_map(m).unwrap();
+        assert_eq!(call.status, CallStatus::NoAnswer);
+        assert_eq!(call.direction, Some(CallDirection::OutboundDial));
+        assert_eq!(call.answered_by, Some(AnsweredBy::Human));
+    }
+
+    #[test]
+    fn test_call_update() {
+        let update = CallUpdate::end(EndCallStatus::Completed);
+        assert!(update.validate().is_ok());
+        assert_eq!(update.params(), [("Status", "completed".to_string())]);
+
+        let update = CallUpdate::redirect("https://example.com/transfer", Method::Post);
+        assert_eq!(
+            update.params(),
+            [
+                ("Url", "https://example.com/transfer".to_string()),
+                ("Method", "POST".to_string()),
+            ]
+        );
+
+        let invalid = CallUpdate {
+            status: Some(EndCallStatus::Canceled),
+            ..update
+        };
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_filter_params() {
+        let from = number("+15005550006");
+        let filter = CallFilter {
+            from: Some(&from),
+            status: Some(CallStatus::InProgress),
+            start_time_before: DateTime::from_timestamp(1709251200, 0),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.params().unwrap(),
+            [
+                ("From", "+15005550006".to_string()),
+                ("Status", "in-progress".to_string()),
+                ("StartTime<", "2024-03-01T00:00:00Z".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_params() {
+        let mut twiml = Twiml::default();
+        twiml.add(&Say {
+            txt: "Hello".to_string(),
+            voice: crate::twiml::Voice::Alice,
+            language: "en".to_string(),
+        });
+        let mut amd = MachineDetection::new(MachineDetectionMode::DetectMessageEnd)
+            .asynchronous("https://example.com/amd", Method::Post);
+        amd.timeout = Some(30);
+        let call = OutboundCall::builder(number("+15005550006"), number("+15005550001"))
+            .twiml(&twiml)
+            .status_callback(
+                "https://example.com/status",
+                Method::Post,
+                StatusCallbackEvent::Ringing | StatusCallbackEvent::Completed,
+            )
+            .record(CallRecording {
+                channels: Some(RecordingChannels::Dual),
+                status_callback: Some("https://example.com/recording"),
+                status_callback_event: RecordingStatusCallbackEvent::InProgress
+                    | RecordingStatusCallbackEvent::Completed,
+                ..Default::default()
+            })
+            .machine_detection(amd)
+            .build()
+            .unwrap();
+
+        let params = call.params();
+        assert_eq!(get(&params, "Twiml"), [twiml.as_twiml().as_str()]);
+        assert!(get(&params, "Url").is_empty());
+        assert_eq!(
+            get(&params, "StatusCallbackEvent"),
+            ["ringing", "completed"]
+        );
+        assert_eq!(get(&params, "StatusCallbackMethod"), ["POST"]);
+        assert_eq!(get(&params, "Record"), ["true"]);
+        assert_eq!(get(&params, "RecordingChannels"), ["dual"]);
+        assert_eq!(
+            get(&params, "RecordingStatusCallbackEvent"),
+            ["in-progress completed"]
+        );
+        assert_eq!(get(&params, "MachineDetection"), ["DetectMessageEnd"]);
+        assert_eq!(get(&params, "MachineDetectionTimeout"), ["30"]);
+        assert_eq!(get(&params, "AsyncAmd"), ["true"]);
+        assert_eq!(
+            get(&params, "AsyncAmdStatusCallback"),
+            ["https://example.com/amd"]
+        );
+    }
+}
@@ -0,0 +1,834 @@
+use crate::call::StatusCallbackEvent;
+use crate::twiml::Method;
+use crate::{
+    de, AccountSid, CallSid, Client, ConferenceSid, ListResource, Page, PhoneNumber, TwilioError,
+    DELETE, GET, NO_PARAMS, PATH_SEGMENT, POST,
+};
+use bitflags::bitflags;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use core::fmt;
+use futures_core::Stream;
+use percent_encoding::utf8_percent_encode;
+use serde::{Deserialize, Deserializer};
+use std::borrow::Cow;
+
+/// The status of a conference.  Statuses this crate does not know about yet are preserved in
+/// `Unknown` rather than failing to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConferenceStatus {
+    /// Created, but waiting for a participant that starts the conference.
+    Init,
+    InProgress,
+    Completed,
+    Unknown(String),
+}
+
+impl AsRef<str> for ConferenceStatus {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Init => "init",
+            Self::InProgress => "in-progress",
+            Self::Completed => "completed",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ConferenceStatus {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        let s = <Cow<'de, str>>::deserialize(de)?;
+        Ok(match &*s {
+            "init" => Self::Init,
+            "in-progress" => Self::InProgress,
+            "completed" => Self::Completed,
+            _ => Self::Unknown(s.into_owned()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Conference {
+    pub sid: ConferenceSid,
+    pub account_sid: AccountSid,
+    /// The name the conference was joined with in `<Dial><Conference>`.
+    pub friendly_name: String,
+    pub status: ConferenceStatus,
+    pub region: Option<String>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_updated: Option<DateTime<Utc>>,
+    /// Why the conference ended, e.g. `last-participant-left`.
+    pub reason_conference_ended: Option<String>,
+    pub call_sid_ending_conference: Option<CallSid>,
+    pub api_version: Option<String>,
+}
+
+impl ListResource for Conference {
+    const LIST_KEY: &'static str = "conferences";
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ParticipantStatus {
+    Queued,
+    Connecting,
+    Ringing,
+    Connected,
+    Complete,
+    Failed,
+    /// A status this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Participant {
+    pub account_sid: AccountSid,
+    pub call_sid: CallSid,
+    pub conference_sid: ConferenceSid,
+    pub label: Option<String>,
+    pub status: ParticipantStatus,
+    #[serde(default)]
+    pub muted: bool,
+    #[serde(default)]
+    pub hold: bool,
+    #[serde(default)]
+    pub coaching: bool,
+    pub call_sid_to_coach: Option<CallSid>,
+    #[serde(default)]
+    pub start_conference_on_enter: bool,
+    #[serde(default)]
+    pub end_conference_on_exit: bool,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_updated: Option<DateTime<Utc>>,
+}
+
+impl ListResource for Participant {
+    const LIST_KEY: &'static str = "participants";
+}
+
+bitflags! {
+    /// Conference events to receive conference status callbacks for.
+    #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+    pub struct ConferenceStatusCallbackEvent: u16 {
+        const Start = 0x001;
+        const End = 0x002;
+        const Join = 0x004;
+        const Leave = 0x008;
+        const Mute = 0x010;
+        const Hold = 0x020;
+        const Modify = 0x040;
+        const Speaker = 0x080;
+        const Announcement = 0x100;
+    }
+}
+
+impl ConferenceStatusCallbackEvent {
+    pub(crate) fn names(self) -> impl Iterator<Item = &'static str> {
+        IntoIterator::into_iter([
+            (Self::Start, "start"),
+            (Self::End, "end"),
+            (Self::Join, "join"),
+            (Self::Leave, "leave"),
+            (Self::Mute, "mute"),
+            (Self::Hold, "hold"),
+            (Self::Modify, "modify"),
+            (Self::Speaker, "speaker"),
+            (Self::Announcement, "announcement"),
+        ])
+        .filter(move |(flag, _)| self.contains(*flag))
+        .map(|(_, name)| name)
+    }
+}
+
+/// When to play a beep as participants enter or leave a conference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Beep {
+    Always,
+    Never,
+    OnEnter,
+    OnExit,
+}
+
+impl AsRef<str> for Beep {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Always => "true",
+            Self::Never => "false",
+            Self::OnEnter => "onEnter",
+            Self::OnExit => "onExit",
+        }
+    }
+}
+
+/// The conference to dial a participant into through [`Client::add_participant`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConferenceRef<'a> {
+    Sid(ConferenceSid),
+    /// The friendly name of a conference.  A conference by this name is created if none is in
+    /// progress yet.
+    Name(&'a str),
+}
+
+impl From<ConferenceSid> for ConferenceRef<'_> {
+    #[inline]
+    fn from(sid: ConferenceSid) -> Self {
+        Self::Sid(sid)
+    }
+}
+
+impl<'a> From<&'a str> for ConferenceRef<'a> {
+    #[inline]
+    fn from(name: &'a str) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl fmt::Display for ConferenceRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sid(sid) => sid.fmt(f),
+            Self::Name(name) => utf8_percent_encode(name, PATH_SEGMENT).fmt(f),
+        }
+    }
+}
+
+/// A participant of a conference, addressed by the SID of their call or by the label they
+/// were added with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticipantRef<'a> {
+    Sid(CallSid),
+    Label(&'a str),
+}
+
+impl From<CallSid> for ParticipantRef<'_> {
+    #[inline]
+    fn from(sid: CallSid) -> Self {
+        Self::Sid(sid)
+    }
+}
+
+impl<'a> From<&'a str> for ParticipantRef<'a> {
+    #[inline]
+    fn from(label: &'a str) -> Self {
+        Self::Label(label)
+    }
+}
+
+impl fmt::Display for ParticipantRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sid(sid) => sid.fmt(f),
+            Self::Label(label) => utf8_percent_encode(label, PATH_SEGMENT).fmt(f),
+        }
+    }
+}
+
+/// A participant to dial into a conference through [`Client::add_participant`].  When the
+/// conference is addressed by name, it is created if it does not exist yet.
+#[derive(Debug, Clone)]
+pub struct OutboundParticipant<'a> {
+    pub from: PhoneNumber,
+    pub to: PhoneNumber,
+    /// A unique name to address the participant by instead of its call SID.
+    pub label: Option<&'a str>,
+    pub status_callback: Option<&'a str>,
+    pub status_callback_method: Option<Method>,
+    pub status_callback_event: StatusCallbackEvent,
+    /// Seconds to let the call ring before giving up, from 5 to 600.
+    pub timeout: Option<u32>,
+    pub muted: Option<bool>,
+    pub beep: Option<Beep>,
+    pub start_conference_on_enter: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    /// TwiML or audio to play while waiting for the conference to start.
+    pub wait_url: Option<&'a str>,
+    pub wait_method: Option<Method>,
+    /// Maximum number of participants in the conference, from 2 to 250.
+    pub max_participants: Option<u32>,
+    /// Whether to record the conference from the moment it starts.
+    pub conference_record: Option<bool>,
+    pub conference_status_callback: Option<&'a str>,
+    pub conference_status_callback_method: Option<Method>,
+    pub conference_status_callback_event: ConferenceStatusCallbackEvent,
+    /// Joins the participant as a coach of the given call, heard only by that call.
+    pub call_sid_to_coach: Option<CallSid>,
+    pub caller_id: Option<PhoneNumber>,
+    /// Maximum length of the participant's call in seconds, up to 24 hours.
+    pub time_limit: Option<u32>,
+}
+
+impl<'a> OutboundParticipant<'a> {
+    pub fn new(from: PhoneNumber, to: PhoneNumber) -> Self {
+        OutboundParticipant {
+            from,
+            to,
+            label: None,
+            status_callback: None,
+            status_callback_method: None,
+            status_callback_event: StatusCallbackEvent::default(),
+            timeout: None,
+            muted: None,
+            beep: None,
+            start_conference_on_enter: None,
+            end_conference_on_exit: None,
+            wait_url: None,
+            wait_method: None,
+            max_participants: None,
+            conference_record: None,
+            conference_status_callback: None,
+            conference_status_callback_method: None,
+            conference_status_callback_event: ConferenceStatusCallbackEvent::default(),
+            call_sid_to_coach: None,
+            caller_id: None,
+            time_limit: None,
+        }
+    }
+
+    fn validate(&self) -> Result<(), TwilioError> {
+        if !self.to.can_receive() {
+            return Err(TwilioError::ValidationError(
+                "To cannot be a short code or alphanumeric sender ID",
+            ));
+        }
+        if self.timeout.is_some_and(|t| !(5..=600).contains(&t)) {
+            return Err(TwilioError::ValidationError(
+                "Timeout must be between 5 and 600 seconds",
+            ));
+        }
+        if self
+            .max_participants
+            .is_some_and(|m| !(2..=250).contains(&m))
+        {
+            return Err(TwilioError::ValidationError(
+                "MaxParticipants must be between 2 and 250",
+            ));
+        }
+        Ok(())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![("From", self.from.to_string()), ("To", self.to.to_string())];
+        let strings = [
+            ("Label", self.label),
+            ("StatusCallback", self.status_callback),
+            ("WaitUrl", self.wait_url),
+            ("ConferenceStatusCallback", self.conference_status_callback),
+        ];
+        params.extend(
+            strings
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        if let Some(sid) = self.call_sid_to_coach {
+            params.push(("CallSidToCoach", sid.to_string()));
+        }
+        if let Some(ref c) = self.caller_id {
+            params.push(("CallerId", c.to_string()));
+        }
+        let methods = [
+            ("StatusCallbackMethod", self.status_callback_method),
+            ("WaitMethod", self.wait_method),
+            (
+                "ConferenceStatusCallbackMethod",
+                self.conference_status_callback_method,
+            ),
+        ];
+        params.extend(
+            methods
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.as_ref().to_string()))),
+        );
+        let bools = [
+            ("Muted", self.muted),
+            ("StartConferenceOnEnter", self.start_conference_on_enter),
+            ("EndConferenceOnExit", self.end_conference_on_exit),
+        ];
+        params.extend(
+            bools
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        let numbers = [
+            ("Timeout", self.timeout),
+            ("MaxParticipants", self.max_participants),
+            ("TimeLimit", self.time_limit),
+        ];
+        params.extend(
+            numbers
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        if let Some(b) = self.beep {
+            params.push(("Beep", b.as_ref().to_string()));
+        }
+        if let Some(r) = self.conference_record {
+            let record = if r {
+                "record-from-start"
+            } else {
+                "do-not-record"
+            };
+            params.push(("ConferenceRecord", record.to_string()));
+        }
+        if self.call_sid_to_coach.is_some() {
+            params.push(("Coaching", "true".to_string()));
+        }
+        params.extend(
+            self.status_callback_event
+                .names()
+                .map(|e| ("StatusCallbackEvent", e.to_string())),
+        );
+        let events = self
+            .conference_status_callback_event
+            .names()
+            .collect::<Vec<_>>();
+        if !events.is_empty() {
+            params.push(("ConferenceStatusCallbackEvent", events.join(" ")));
+        }
+        params
+    }
+}
+
+/// Changes to apply to a conference participant through [`Client::update_participant`].
+#[derive(Debug, Clone, Default)]
+pub struct ParticipantUpdate<'a> {
+    pub muted: Option<bool>,
+    pub hold: Option<bool>,
+    /// Music or TwiML to play to the participant while on hold.
+    pub hold_url: Option<&'a str>,
+    pub hold_method: Option<Method>,
+    /// Audio or TwiML to play to the participant only.
+    pub announce_url: Option<&'a str>,
+    pub announce_method: Option<Method>,
+    pub beep_on_exit: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    pub coaching: Option<bool>,
+    pub call_sid_to_coach: Option<CallSid>,
+}
+
+impl ParticipantUpdate<'_> {
+    fn validate(&self) -> Result<(), TwilioError> {
+        if self.hold_url.is_some() && self.hold != Some(true) {
+            return Err(TwilioError::ValidationError(
+                "HoldUrl can only be set when putting a participant on hold",
+            ));
+        }
+        if self.coaching == Some(true) && self.call_sid_to_coach.is_none() {
+            return Err(TwilioError::ValidationError(
+                "CallSidToCoach is required when coaching",
+            ));
+        }
+        Ok(())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        let strings = [
+            ("HoldUrl", self.hold_url),
+            ("AnnounceUrl", self.announce_url),
+        ];
+        params.extend(
+            strings
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        if let Some(sid) = self.call_sid_to_coach {
+            params.push(("CallSidToCoach", sid.to_string()));
+        }
+        let methods = [
+            ("HoldMethod", self.hold_method),
+            ("AnnounceMethod", self.announce_method),
+        ];
+        params.extend(
+            methods
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.as_ref().to_string()))),
+        );
+        let bools = [
+            ("Muted", self.muted),
+            ("Hold", self.hold),
+            ("BeepOnExit", self.beep_on_exit),
+            ("EndConferenceOnExit", self.end_conference_on_exit),
+            ("Coaching", self.coaching),
+        ];
+        params.extend(
+            bools
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        params
+    }
+}
+
+/// Narrows down the conferences returned by [`Client::list_conferences`].
+#[derive(Debug, Clone, Default)]
+pub struct ConferenceFilter<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub status: Option<ConferenceStatus>,
+    /// Only conferences created on this day (UTC).
+    pub date_created: Option<NaiveDate>,
+    /// Only conferences created on or before this time.
+    pub date_created_before: Option<DateTime<Utc>>,
+    /// Only conferences created on or after this time.
+    pub date_created_after: Option<DateTime<Utc>>,
+    /// Number of conferences per page, from 1 to 1000.
+    pub page_size: Option<u32>,
+}
+
+impl ConferenceFilter<'_> {
+    fn params(&self) -> Result<Vec<(&'static str, String)>, TwilioError> {
+        let mut params = Vec::new();
+        if let Some(name) = self.friendly_name {
+            params.push(("FriendlyName", name.to_string()));
+        }
+        if let Some(ref status) = self.status {
+            params.push(("Status", status.as_ref().to_string()));
+        }
+        if let Some(d) = self.date_created {
+            params.push(("DateCreated", d.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(t) = self.date_created_before {
+            params.push(("DateCreated<", t.to_rfc3339_opts(SecondsFormat::Secs, true)));
+        }
+        if let Some(t) = self.date_created_after {
+            params.push(("DateCreated>", t.to_rfc3339_opts(SecondsFormat::Secs, true)));
+        }
+        match self.page_size {
+            Some(size @ 1..=1000) => params.push(("PageSize", size.to_string())),
+            Some(_) => {
+                return Err(TwilioError::ValidationError(
+                    "PageSize must be between 1 and 1000",
+                ))
+            }
+            None => {}
+        }
+        Ok(params)
+    }
+}
+
+impl Client {
+    /// Streams every conference matching `filter`, most recent first, fetching further pages
+    /// as the stream is consumed.
+    pub fn list_conferences(
+        &self,
+        filter: &ConferenceFilter<'_>,
+    ) -> Result<impl Stream<Item = Result<Conference, TwilioError>> + '_, TwilioError> {
+        Ok(self.paginate("Conferences", &filter.params()?))
+    }
+
+    /// Fetches the first page of conferences matching `filter`.  Pass the page's
+    /// `next_page_uri` to [`Client::fetch_page`] to continue.
+    pub async fn list_conferences_page(
+        &self,
+        filter: &ConferenceFilter<'_>,
+    ) -> Result<Page<Conference>, TwilioError> {
+        self.first_page("Conferences", &filter.params()?).await
+    }
+
+    pub async fn fetch_conference(
+        &self,
+        conference_sid: &ConferenceSid,
+    ) -> Result<Conference, TwilioError> {
+        self.send_request(GET, &format!("Conferences/{}", conference_sid), NO_PARAMS)
+            .await
+    }
+
+    /// Ends a conference, disconnecting every participant.
+    pub async fn end_conference(
+        &self,
+        conference_sid: &ConferenceSid,
+    ) -> Result<Conference, TwilioError> {
+        let endpoint = format!("Conferences/{}", conference_sid);
+        self.send_request(POST, &endpoint, &[("Status", "completed")])
+            .await
+    }
+
+    /// Plays the audio or TwiML at `url` to every participant of a conference.
+    pub async fn announce_to_conference(
+        &self,
+        conference_sid: &ConferenceSid,
+        url: &str,
+        method: Method,
+    ) -> Result<Conference, TwilioError> {
+        let endpoint = format!("Conferences/{}", conference_sid);
+        let params = [("AnnounceUrl", url), ("AnnounceMethod", method.as_ref())];
+        self.send_request(POST, &endpoint, &params).await
+    }
+
+    /// Streams every participant currently in a conference.
+    pub fn list_participants(
+        &self,
+        conference_sid: &ConferenceSid,
+    ) -> impl Stream<Item = Result<Participant, TwilioError>> + '_ {
+        self.paginate(
+            &format!("Conferences/{}/Participants", conference_sid),
+            NO_PARAMS,
+        )
+    }
+
+    /// Fetches a participant of a conference.
+    pub async fn fetch_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        participant: ParticipantRef<'_>,
+    ) -> Result<Participant, TwilioError> {
+        let endpoint = format!(
+            "Conferences/{}/Participants/{}",
+            conference_sid, participant
+        );
+        self.send_request(GET, &endpoint, NO_PARAMS).await
+    }
+
+    /// Dials a new participant into a conference, creating the conference first if it is
+    /// addressed by a name that is not in use.
+    pub async fn add_participant(
+        &self,
+        conference: ConferenceRef<'_>,
+        participant: &OutboundParticipant<'_>,
+    ) -> Result<Participant, TwilioError> {
+        participant.validate()?;
+        let endpoint = format!("Conferences/{}/Participants", conference);
+        self.send_request(POST, &endpoint, &participant.params())
+            .await
+    }
+
+    /// Modifies a participant of a conference.
+    pub async fn update_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        participant: ParticipantRef<'_>,
+        update: &ParticipantUpdate<'_>,
+    ) -> Result<Participant, TwilioError> {
+        update.validate()?;
+        let endpoint = format!(
+            "Conferences/{}/Participants/{}",
+            conference_sid, participant
+        );
+        self.send_request(POST, &endpoint, &update.params()).await
+    }
+
+    pub async fn mute_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        participant: ParticipantRef<'_>,
+        muted: bool,
+    ) -> Result<Participant, TwilioError> {
+        let update = ParticipantUpdate {
+            muted: Some(muted),
+            ..Default::default()
+        };
+        self.update_participant(conference_sid, participant, &update)
+            .await
+    }
+
+    /// Puts a participant on hold, playing `hold_url` to them if given, or takes them off hold
+    /// when `hold` is false.
+    pub async fn hold_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        participant: ParticipantRef<'_>,
+        hold: bool,
+        hold_url: Option<&str>,
+    ) -> Result<Participant, TwilioError> {
+        let update = ParticipantUpdate {
+            hold: Some(hold),
+            hold_url,
+            ..Default::default()
+        };
+        self.update_participant(conference_sid, participant, &update)
+            .await
+    }
+
+    /// Makes a participant a coach of `call_sid_to_coach`, so that only that call hears them,
+    /// or makes them a regular participant again when `None`.
+    pub async fn coach_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        participant: ParticipantRef<'_>,
+        call_sid_to_coach: Option<CallSid>,
+    ) -> Result<Participant, TwilioError> {
+        let update = ParticipantUpdate {
+            coaching: Some(call_sid_to_coach.is_some()),
+            call_sid_to_coach,
+            ..Default::default()
+        };
+        self.update_participant(conference_sid, participant, &update)
+            .await
+    }
+
+    /// Plays the audio or TwiML at `url` to a single participant.
+    pub async fn announce_to_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        participant: ParticipantRef<'_>,
+        url: &str,
+        method: Method,
+    ) -> Result<Participant, TwilioError> {
+        let update = ParticipantUpdate {
+            announce_url: Some(url),
+            announce_method: Some(method),
+            ..Default::default()
+        };
+        self.update_participant(conference_sid, participant, &update)
+            .await
+    }
+
+    /// Removes a participant from a conference, hanging up their call.
+    pub async fn kick_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        participant: ParticipantRef<'_>,
+    ) -> Result<(), TwilioError> {
+        let endpoint = format!(
+            "Conferences/{}/Participants/{}",
+            conference_sid, participant
+        );
+        self.send_request_no_content(DELETE, &endpoint, NO_PARAMS)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_participant_params() {
+        let participant = OutboundParticipant {
+            label: Some("agent"),
+            beep: Some(Beep::OnEnter),
+            end_conference_on_exit: Some(true),
+            call_sid_to_coach: "CA11111111111111111111111111111111".parse().ok(),
+            status_callback_event: StatusCallbackEvent::Ringing | StatusCallbackEvent::Answered,
+            conference_status_callback_event: ConferenceStatusCallbackEvent::Join
+                | ConferenceStatusCallbackEvent::Leave,
+            ..OutboundParticipant::new(
+                "+15005550006".parse().unwrap(),
+                "client:agent".parse().unwrap(),
+            )
+        };
+        participant.validate().unwrap();
+        let params = participant.params();
+        for expected in [
+            ("To", "client:agent"),
+            ("Label", "agent"),
+            ("Beep", "onEnter"),
+            ("EndConferenceOnExit", "true"),
+            ("CallSidToCoach", "CA11111111111111111111111111111111"),
+            ("Coaching", "true"),
+            ("StatusCallbackEvent", "ringing"),
+            ("StatusCallbackEvent", "answered"),
+            ("ConferenceStatusCallbackEvent", "join leave"),
+        ] {
+            assert!(
+                params.iter().any(|(k, v)| (*k, v.as_str()) == expected),
+                "missing {:?}",
+                expected
+            );
+        }
+
+        let participant = OutboundParticipant {
+            max_participants: Some(300),
+            ..OutboundParticipant::new(
+                "+15005550006".parse().unwrap(),
+                "+15005550001".parse().unwrap(),
+            )
+        };
+        assert!(participant.validate().is_err());
+    }
+
+    #[test]
+    fn test_conference_ref() {
+        let sid: ConferenceSid = "CFaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".parse().unwrap();
+        assert_eq!(
+            ConferenceRef::from(sid).to_string(),
+            "CFaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+        );
+        assert_eq!(
+            ConferenceRef::from("Sales / Q3").to_string(),
+            "Sales%20%2F%20Q3"
+        );
+    }
+
+    #[test]
+    fn test_participant_update() {
+        let update = ParticipantUpdate {
+            hold: Some(true),
+            hold_url: Some("https://example.com/hold.mp3"),
+            hold_method: Some(Method::Get),
+            ..Default::default()
+        };
+        update.validate().unwrap();
+        assert_eq!(
+            update.params(),
+            [
+                ("HoldUrl", "https://example.com/hold.mp3".to_string()),
+                ("HoldMethod", "GET".to_string()),
+                ("Hold", "true".to_string()),
+            ]
+        );
+
+        let update = ParticipantUpdate {
+            hold_url: Some("https://example.com/hold.mp3"),
+            ..Default::default()
+        };
+        assert!(update.validate().is_err());
+        let update = ParticipantUpdate {
+            coaching: Some(true),
+            ..Default::default()
+        };
+        assert!(update.validate().is_err());
+    }
+
+    #[test]
+    fn test_deserialize_participant() {
+        let s = r#"{
+            "account_sid": "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "call_sid": "CAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "label": "customer",
+            "call_sid_to_coach": null,
+            "coaching": false,
+            "conference_sid": "CFaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "date_created": "Fri, 18 Feb 2011 21:07:19 +0000",
+            "date_updated": "Fri, 18 Feb 2011 21:07:19 +0000",
+            "end_conference_on_exit": false,
+            "muted": true,
+            "hold": false,
+            "status": "connected",
+            "start_conference_on_enter": true
+        }"#;
+        let p: Participant = serde_json::from_str(s).unwrap();
+        assert_eq!(p.status, ParticipantStatus::Connected);
+        assert!(p.muted);
+        assert_eq!(p.label.as_deref(), Some("customer"));
+
+        let s = s.replace(r#""status": "connected""#, r#""status": "whispering""#);
+        let p: Participant = serde_json::from_str(&s).unwrap();
+        assert_eq!(p.status, ParticipantStatus::Unknown);
+    }
+
+    #[test]
+    fn test_deserialize_conference() {
+        let s = r#"{
+            "account_sid": "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "sid": "CFaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "friendly_name": "Sales Standup",
+            "status": "in-progress",
+            "region": "us1",
+            "date_created": "Fri, 18 Feb 2011 21:07:19 +0000",
+            "date_updated": "Fri, 18 Feb 2011 21:07:19 +0000"
+        }"#;
+        let c: Conference = serde_json::from_str(s).unwrap();
+        assert_eq!(c.status, ConferenceStatus::InProgress);
+
+        let s = s.replace("in-progress", "archived");
+        let c: Conference = serde_json::from_str(&s).unwrap();
+        assert_eq!(c.status, ConferenceStatus::Unknown("archived".to_string()));
+        assert_eq!(c.status.as_ref(), "archived");
+    }
+}
@@ -0,0 +1,75 @@
+//! Deserialization helpers for the formats Twilio uses in REST responses.
+
+use chrono::{DateTime, Utc};
+use serde::de::value::StrDeserializer;
+use serde::de::{DeserializeOwned, Error, IntoDeserializer as _};
+use serde::{Deserialize, Deserializer};
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parses Twilio's RFC 2822 timestamps, e.g. `Thu, 24 Aug 2023 05:01:45 +0000`.
+pub(crate) fn parse_rfc2822(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(s)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Deserializes an optional RFC 2822 timestamp.
+pub(crate) fn rfc2822_opt<'de, D: Deserializer<'de>>(
+    de: D,
+) -> Result<Option<DateTime<Utc>>, D::Error> {
+    match <Option<Cow<'de, str>>>::deserialize(de)? {
+        Some(s) => parse_rfc2822(&s)
+            .map(Some)
+            .ok_or_else(|| D::Error::custom(format!("Invalid RFC 2822 timestamp '{s}'"))),
+        None => Ok(None),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StrOrNum<'a> {
+    #[serde(borrow)]
+    Str(Cow<'a, str>),
+    Num(serde_json::Number),
+}
+
+/// Deserializes an optional number that Twilio may send as either a JSON number or a string,
+/// as it does for e.g. `num_segments`.
+pub(crate) fn from_str_opt<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let s = match <Option<StrOrNum<'de>>>::deserialize(de)? {
+        Some(StrOrNum::Str(s)) => s,
+        Some(StrOrNum::Num(n)) => Cow::Owned(n.to_string()),
+        None => return Ok(None),
+    };
+    s.parse().map(Some).map_err(D::Error::custom)
+}
+
+/// Deserializes an optional value that Twilio sends as an empty string when it is unset, as it
+/// does for e.g. the application SIDs of a phone number.
+pub(crate) fn empty_as_none<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    match <Option<Cow<'de, str>>>::deserialize(de)? {
+        Some(s) if !s.is_empty() => {
+            let de: StrDeserializer<'_, D::Error> = s.as_ref().into_deserializer();
+            T::deserialize(de).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Parses a webhook parameter into a type that is deserialized from the same string in REST
+/// responses, such as a `kebab-case` enum.
+pub(crate) fn from_param<'a, T: Deserialize<'a>>(s: &'a str) -> Option<T> {
+    let de: StrDeserializer<'a, serde::de::value::Error> = s.into_deserializer();
+    T::deserialize(de).ok()
+}
@@ -0,0 +1,92 @@
+use crate::{response_error, Client, TwilioError, GET, NO_PARAMS};
+use bytes::Bytes;
+use headers::{ContentLength, ContentType, HeaderMapExt};
+use http_body_util::{BodyExt as _, Either, Empty};
+use hyper::body::Incoming;
+use hyper::header::LOCATION;
+use tokio::io::{AsyncWrite, AsyncWriteExt as _};
+use url::Url;
+
+/// Maximum number of redirects followed to reach a file.
+const MAX_REDIRECTS: usize = 5;
+
+/// A file served by Twilio, such as message media, whose body has not been read yet.
+#[derive(Debug)]
+pub struct Download {
+    pub content_type: Option<mime::Mime>,
+    /// Size of the file in bytes, if the server reported it.
+    pub content_length: Option<u64>,
+    body: Incoming,
+}
+
+impl Download {
+    /// Streams the file into `writer` and returns the number of bytes written.
+    pub async fn write_to<W>(self, writer: &mut W) -> Result<u64, TwilioError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut this = self;
+        let mut written = 0;
+        while let Some(data) = this.next_chunk().await? {
+            writer
+                .write_all(&data)
+                .await
+                .map_err(TwilioError::IoError)?;
+            written += data.len() as u64;
+        }
+        writer.flush().await.map_err(TwilioError::IoError)?;
+        Ok(written)
+    }
+
+    /// Reads the next chunk of the body, or `None` once all of it has been read.
+    pub(crate) async fn next_chunk(&mut self) -> Result<Option<Bytes>, TwilioError> {
+        while let Some(frame) = self.body.frame().await {
+            let frame = frame.map_err(TwilioError::ReadResponseError)?;
+            if let Ok(data) = frame.into_data() {
+                return Ok(Some(data));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Client {
+    /// Requests the file at `url`, following redirects to wherever Twilio stores it.
+    pub(crate) async fn download(&self, url: &str) -> Result<Download, TwilioError> {
+        let mut resp = self.execute_allowing_redirects(GET, url, NO_PARAMS).await?;
+        let mut current = url.to_string();
+        let mut redirects = 0;
+        while resp.status().is_redirection() {
+            let status = resp.status();
+            // `Location` may be relative to the URL that was redirected.
+            current = match resp.headers().get(LOCATION).map(|l| l.to_str()) {
+                Some(Ok(l)) if redirects < MAX_REDIRECTS => Url::parse(&current)
+                    .and_then(|base| base.join(l))
+                    .map_err(|_| TwilioError::HTTPError(status))?
+                    .into(),
+                _ => return Err(TwilioError::HTTPError(status)),
+            };
+            redirects += 1;
+
+            // The target is a pre-signed URL on a storage host, which must not be sent our
+            // credentials.
+            let req = hyper::Request::get(&current)
+                .body(Either::Left(Empty::new()))
+                .map_err(|_| TwilioError::HTTPError(status))?;
+            resp = self
+                .http_client
+                .request(req)
+                .await
+                .map_err(TwilioError::RequestError)?;
+            if !resp.status().is_success() && !resp.status().is_redirection() {
+                return Err(response_error(resp).await);
+            }
+        }
+
+        Ok(Download {
+            content_type: resp.headers().typed_get::<ContentType>().map(Into::into),
+            content_length: resp.headers().typed_get::<ContentLength>().map(|l| l.0),
+            body: resp.into_body(),
+        })
+    }
+}
@@ -0,0 +1,224 @@
+use hyper::StatusCode;
+use serde::{Deserialize, Deserializer};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// The JSON error body Twilio returns alongside a non-2xx response.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ApiError {
+    pub code: Option<ErrorCode>,
+    pub message: String,
+    pub more_info: Option<String>,
+    pub status: u16,
+}
+
+impl ApiError {
+    /// The HTTP status reported in the error body.
+    #[inline]
+    pub fn http_status(&self) -> Option<StatusCode> {
+        StatusCode::from_u16(self.status).ok()
+    }
+
+    #[inline]
+    pub fn is_retryable(&self) -> bool {
+        match self.code {
+            Some(ErrorCode::Unknown(_)) | None => match self.http_status() {
+                Some(s) => s == StatusCode::TOO_MANY_REQUESTS || s.is_server_error(),
+                None => false,
+            },
+            Some(code) => code.is_retryable(),
+        }
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.code {
+            Some(code) => write!(
+                f,
+                "Twilio error {} ({}): {}",
+                code, self.status, self.message
+            ),
+            None => write!(f, "Twilio error ({}): {}", self.status, self.message),
+        }
+    }
+}
+
+impl Error for ApiError {}
+
+/// Twilio error codes that callers commonly need to act on.  Any other code is preserved in
+/// `Unknown`; see <https://www.twilio.com/docs/api/errors> for the full list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// 10001: Account is not active.
+    AccountNotActive,
+    /// 20003: Authentication failed.
+    AuthenticationFailed,
+    /// 20404: The requested resource was not found.
+    NotFound,
+    /// 20429: Too many requests.
+    TooManyRequests,
+    /// 20500: Internal server error.
+    InternalServerError,
+    /// 20503: Service unavailable.
+    ServiceUnavailable,
+    /// 21211: Invalid `To` phone number.
+    InvalidToNumber,
+    /// 21212: Invalid `From` phone number.
+    InvalidFromNumber,
+    /// 21408: Permission to send to this region has not been enabled.
+    RegionNotEnabled,
+    /// 21602: Message body is required.
+    MessageBodyRequired,
+    /// 21606: The `From` number is not a valid message-capable Twilio number.
+    FromNumberNotCapable,
+    /// 21608: Trial accounts can only send to verified numbers.
+    UnverifiedTrialNumber,
+    /// 21610: The recipient has unsubscribed (replied STOP).
+    UnsubscribedRecipient,
+    /// 21614: The `To` number is not a valid mobile number.
+    NotMobileNumber,
+    /// 21617: The message body exceeds the 1600 character limit.
+    BodyTooLong,
+    /// 30001: Message queue overflow.
+    QueueOverflow,
+    /// 30002: Account suspended.
+    AccountSuspended,
+    /// 30003: Unreachable destination handset.
+    UnreachableHandset,
+    /// 30004: Message blocked.
+    MessageBlocked,
+    /// 30005: Unknown destination handset.
+    UnknownHandset,
+    /// 30006: Landline or unreachable carrier.
+    LandlineOrUnreachableCarrier,
+    /// 30007: Message filtered by the carrier.
+    MessageFiltered,
+    /// 30008: Unknown delivery error.
+    UnknownDeliveryError,
+    Unknown(u32),
+}
+
+impl ErrorCode {
+    #[inline]
+    pub fn code(self) -> u32 {
+        match self {
+            Self::AccountNotActive => 10001,
+            Self::AuthenticationFailed => 20003,
+            Self::NotFound => 20404,
+            Self::TooManyRequests => 20429,
+            Self::InternalServerError => 20500,
+            Self::ServiceUnavailable => 20503,
+            Self::InvalidToNumber => 21211,
+            Self::InvalidFromNumber => 21212,
+            Self::RegionNotEnabled => 21408,
+            Self::MessageBodyRequired => 21602,
+            Self::FromNumberNotCapable => 21606,
+            Self::UnverifiedTrialNumber => 21608,
+            Self::UnsubscribedRecipient => 21610,
+            Self::NotMobileNumber => 21614,
+            Self::BodyTooLong => 21617,
+            Self::QueueOverflow => 30001,
+            Self::AccountSuspended => 30002,
+            Self::UnreachableHandset => 30003,
+            Self::MessageBlocked => 30004,
+            Self::UnknownHandset => 30005,
+            Self::LandlineOrUnreachableCarrier => 30006,
+            Self::MessageFiltered => 30007,
+            Self::UnknownDeliveryError => 30008,
+            Self::Unknown(c) => c,
+        }
+    }
+
+    /// Whether a request that failed with this code may succeed if sent again unchanged.
+    #[inline]
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::TooManyRequests
+                | Self::InternalServerError
+                | Self::ServiceUnavailable
+                | Self::QueueOverflow
+        )
+    }
+}
+
+impl From<u32> for ErrorCode {
+    #[inline]
+    fn from(code: u32) -> Self {
+        match code {
+            10001 => Self::AccountNotActive,
+            20003 => Self::AuthenticationFailed,
+            20404 => Self::NotFound,
+            20429 => Self::TooManyRequests,
+            20500 => Self::InternalServerError,
+            20503 => Self::ServiceUnavailable,
+            21211 => Self::InvalidToNumber,
+            21212 => Self::InvalidFromNumber,
+            21408 => Self::RegionNotEnabled,
+            21602 => Self::MessageBodyRequired,
+            21606 => Self::FromNumberNotCapable,
+            21608 => Self::UnverifiedTrialNumber,
+            21610 => Self::UnsubscribedRecipient,
+            21614 => Self::NotMobileNumber,
+            21617 => Self::BodyTooLong,
+            30001 => Self::QueueOverflow,
+            30002 => Self::AccountSuspended,
+            30003 => Self::UnreachableHandset,
+            30004 => Self::MessageBlocked,
+            30005 => Self::UnknownHandset,
+            30006 => Self::LandlineOrUnreachableCarrier,
+            30007 => Self::MessageFiltered,
+            30008 => Self::UnknownDeliveryError,
+            c => Self::Unknown(c),
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        u32::deserialize(de).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_api_error() {
+        let s = r#"{
+            "code": 21211,
+            "message": "The 'To' number 5551234 is not a valid phone number.",
+            "more_info": "https://www.twilio.com/docs/errors/21211",
+            "status": 400
+        }"#;
+        let err: ApiError = serde_json::from_str(s).unwrap();
+        assert_eq!(err.code, Some(ErrorCode::InvalidToNumber));
+        assert_eq!(err.http_status(), Some(StatusCode::BAD_REQUEST));
+        assert!(!err.is_retryable());
+
+        let s =
+            r#"{"code": 20429, "message": "Too Many Requests", "more_info": null, "status": 429}"#;
+        let err: ApiError = serde_json::from_str(s).unwrap();
+        assert_eq!(err.code, Some(ErrorCode::TooManyRequests));
+        assert!(err.is_retryable());
+    }
+
+    #[test]
+    fn test_unknown_code_falls_back_to_status() {
+        let s = r#"{"code": 99999, "message": "Oops", "more_info": null, "status": 502}"#;
+        let err: ApiError = serde_json::from_str(s).unwrap();
+        assert_eq!(err.code, Some(ErrorCode::Unknown(99999)));
+        assert_eq!(err.code.unwrap().code(), 99999);
+        assert!(err.is_retryable());
+    }
+}
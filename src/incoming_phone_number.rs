@@ -0,0 +1,374 @@
+use crate::twiml::Method;
+use crate::{
+    de, AccountSid, AddressSid, ApplicationSid, BundleSid, Client, ListResource, Page, PhoneNumber,
+    PhoneNumberSid, Sid, TrunkSid, TwilioError, DELETE, GET, NO_PARAMS, POST,
+};
+use chrono::{DateTime, Utc};
+use futures_core::Stream;
+use serde::Deserialize;
+
+/// What a phone number can be used for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct PhoneNumberCapabilities {
+    pub voice: bool,
+    #[serde(alias = "SMS")]
+    pub sms: bool,
+    #[serde(alias = "MMS")]
+    pub mms: bool,
+    pub fax: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum EmergencyStatus {
+    Active,
+    Inactive,
+}
+
+impl AsRef<str> for EmergencyStatus {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Active => "Active",
+            Self::Inactive => "Inactive",
+        }
+    }
+}
+
+/// A phone number owned by the account.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IncomingPhoneNumber {
+    pub sid: PhoneNumberSid,
+    pub account_sid: AccountSid,
+    pub phone_number: PhoneNumber,
+    pub friendly_name: Option<String>,
+    #[serde(default)]
+    pub capabilities: PhoneNumberCapabilities,
+    pub sms_url: Option<String>,
+    pub sms_method: Option<Method>,
+    pub sms_fallback_url: Option<String>,
+    pub sms_fallback_method: Option<Method>,
+    #[serde(default, deserialize_with = "de::empty_as_none")]
+    pub sms_application_sid: Option<ApplicationSid>,
+    pub voice_url: Option<String>,
+    pub voice_method: Option<Method>,
+    pub voice_fallback_url: Option<String>,
+    pub voice_fallback_method: Option<Method>,
+    #[serde(default, deserialize_with = "de::empty_as_none")]
+    pub voice_application_sid: Option<ApplicationSid>,
+    #[serde(default)]
+    pub voice_caller_id_lookup: bool,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<Method>,
+    #[serde(default, deserialize_with = "de::empty_as_none")]
+    pub trunk_sid: Option<TrunkSid>,
+    pub emergency_status: Option<EmergencyStatus>,
+    #[serde(default, deserialize_with = "de::empty_as_none")]
+    pub emergency_address_sid: Option<AddressSid>,
+    /// Which kind of address, if any, regulations require for the number: `none`, `any`,
+    /// `local` or `foreign`.
+    pub address_requirements: Option<String>,
+    #[serde(default, deserialize_with = "de::empty_as_none")]
+    pub address_sid: Option<AddressSid>,
+    #[serde(default, deserialize_with = "de::empty_as_none")]
+    pub bundle_sid: Option<BundleSid>,
+    pub origin: Option<String>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_updated: Option<DateTime<Utc>>,
+    pub api_version: Option<String>,
+}
+
+impl ListResource for IncomingPhoneNumber {
+    const LIST_KEY: &'static str = "incoming_phone_numbers";
+}
+
+/// How incoming calls and messages to a phone number are handled, as set through
+/// [`Client::update_phone_number`] or [`Client::purchase_phone_number`].  Fields left unset
+/// keep their current value.  SID fields are set to `Some(None)` to clear them, e.g. to
+/// detach the number from a TwiML application or SIP trunk.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneNumberSettings<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub sms_url: Option<&'a str>,
+    pub sms_method: Option<Method>,
+    pub sms_fallback_url: Option<&'a str>,
+    pub sms_fallback_method: Option<Method>,
+    /// Handles messages with a TwiML application instead of `sms_url`.
+    pub sms_application_sid: Option<Option<ApplicationSid>>,
+    pub voice_url: Option<&'a str>,
+    pub voice_method: Option<Method>,
+    pub voice_fallback_url: Option<&'a str>,
+    pub voice_fallback_method: Option<Method>,
+    /// Handles calls with a TwiML application instead of `voice_url`.
+    pub voice_application_sid: Option<Option<ApplicationSid>>,
+    /// Whether to look up the caller's name for incoming calls, at extra cost.
+    pub voice_caller_id_lookup: Option<bool>,
+    pub status_callback: Option<&'a str>,
+    pub status_callback_method: Option<Method>,
+    /// Routes calls through an Elastic SIP trunk, replacing every voice setting.
+    pub trunk_sid: Option<Option<TrunkSid>>,
+    pub emergency_address_sid: Option<Option<AddressSid>>,
+    pub emergency_status: Option<EmergencyStatus>,
+    /// Address registered for the number, where regulations require one.
+    pub address_sid: Option<Option<AddressSid>>,
+    /// Regulatory bundle proving the owner's identity, where regulations require one.
+    pub bundle_sid: Option<Option<BundleSid>>,
+}
+
+impl PhoneNumberSettings<'_> {
+    /// Rejects settings that point calls or messages at both a URL and an application.
+    /// Clearing one while setting the other is how a number is switched between them.
+    pub(crate) fn validate(&self) -> Result<(), TwilioError> {
+        let is_set = |url: Option<&str>| url.is_some_and(|url| !url.is_empty());
+        if is_set(self.voice_url) && matches!(self.voice_application_sid, Some(Some(_))) {
+            return Err(TwilioError::ValidationError(
+                "VoiceUrl and VoiceApplicationSid cannot both be set",
+            ));
+        }
+        if is_set(self.sms_url) && matches!(self.sms_application_sid, Some(Some(_))) {
+            return Err(TwilioError::ValidationError(
+                "SmsUrl and SmsApplicationSid cannot both be set",
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn push_params(&self, params: &mut Vec<(&'static str, String)>) {
+        let strings = [
+            ("FriendlyName", self.friendly_name),
+            ("SmsUrl", self.sms_url),
+            ("SmsFallbackUrl", self.sms_fallback_url),
+            ("SmsApplicationSid", clearable(&self.sms_application_sid)),
+            ("VoiceUrl", self.voice_url),
+            ("VoiceFallbackUrl", self.voice_fallback_url),
+            (
+                "VoiceApplicationSid",
+                clearable(&self.voice_application_sid),
+            ),
+            ("StatusCallback", self.status_callback),
+            ("TrunkSid", clearable(&self.trunk_sid)),
+            (
+                "EmergencyAddressSid",
+                clearable(&self.emergency_address_sid),
+            ),
+            ("AddressSid", clearable(&self.address_sid)),
+            ("BundleSid", clearable(&self.bundle_sid)),
+        ];
+        params.extend(
+            strings
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        let methods = [
+            ("SmsMethod", self.sms_method),
+            ("SmsFallbackMethod", self.sms_fallback_method),
+            ("VoiceMethod", self.voice_method),
+            ("VoiceFallbackMethod", self.voice_fallback_method),
+            ("StatusCallbackMethod", self.status_callback_method),
+        ];
+        params.extend(
+            methods
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.as_ref().to_string()))),
+        );
+        if let Some(l) = self.voice_caller_id_lookup {
+            params.push(("VoiceCallerIdLookup", l.to_string()));
+        }
+        if let Some(s) = self.emergency_status {
+            params.push(("EmergencyStatus", s.as_ref().to_string()));
+        }
+    }
+}
+
+/// The value of a SID setting that can be cleared by sending it empty.
+fn clearable<K>(setting: &Option<Option<Sid<K>>>) -> Option<&str> {
+    setting
+        .as_ref()
+        .map(|sid| sid.as_ref().map_or("", Sid::as_str))
+}
+
+/// Narrows down the phone numbers returned by [`Client::list_phone_numbers`].
+#[derive(Debug, Clone, Default)]
+pub struct PhoneNumberFilter<'a> {
+    /// Only numbers containing this pattern, which may use `*` as a wildcard digit.
+    pub phone_number: Option<&'a str>,
+    pub friendly_name: Option<&'a str>,
+    /// `twilio` for numbers bought from Twilio, `hosted` for numbers hosted on Twilio.
+    pub origin: Option<&'a str>,
+    /// Number of phone numbers per page, from 1 to 1000.
+    pub page_size: Option<u32>,
+}
+
+impl PhoneNumberFilter<'_> {
+    fn params(&self) -> Result<Vec<(&'static str, String)>, TwilioError> {
+        let mut params = Vec::new();
+        if let Some(n) = self.phone_number {
+            params.push(("PhoneNumber", n.to_string()));
+        }
+        if let Some(n) = self.friendly_name {
+            params.push(("FriendlyName", n.to_string()));
+        }
+        if let Some(o) = self.origin {
+            params.push(("Origin", o.to_string()));
+        }
+        match self.page_size {
+            Some(size @ 1..=1000) => params.push(("PageSize", size.to_string())),
+            Some(_) => {
+                return Err(TwilioError::ValidationError(
+                    "PageSize must be between 1 and 1000",
+                ))
+            }
+            None => {}
+        }
+        Ok(params)
+    }
+}
+
+impl Client {
+    /// Streams every phone number owned by the account that matches `filter`.
+    pub fn list_phone_numbers(
+        &self,
+        filter: &PhoneNumberFilter<'_>,
+    ) -> Result<impl Stream<Item = Result<IncomingPhoneNumber, TwilioError>> + '_, TwilioError>
+    {
+        Ok(self.paginate("IncomingPhoneNumbers", &filter.params()?))
+    }
+
+    /// Fetches the first page of phone numbers matching `filter`.  Pass the page's
+    /// `next_page_uri` to [`Client::fetch_page`] to continue.
+    pub async fn list_phone_numbers_page(
+        &self,
+        filter: &PhoneNumberFilter<'_>,
+    ) -> Result<Page<IncomingPhoneNumber>, TwilioError> {
+        self.first_page("IncomingPhoneNumbers", &filter.params()?)
+            .await
+    }
+
+    pub async fn fetch_phone_number(
+        &self,
+        phone_number_sid: &PhoneNumberSid,
+    ) -> Result<IncomingPhoneNumber, TwilioError> {
+        let endpoint = format!("IncomingPhoneNumbers/{}", phone_number_sid);
+        self.send_request(GET, &endpoint, NO_PARAMS).await
+    }
+
+    pub async fn update_phone_number(
+        &self,
+        phone_number_sid: &PhoneNumberSid,
+        settings: &PhoneNumberSettings<'_>,
+    ) -> Result<IncomingPhoneNumber, TwilioError> {
+        settings.validate()?;
+        let mut params = Vec::new();
+        settings.push_params(&mut params);
+        let endpoint = format!("IncomingPhoneNumbers/{}", phone_number_sid);
+        self.send_request(POST, &endpoint, &params).await
+    }
+
+    /// Releases a phone number from the account.  This cannot be undone: the number may be
+    /// handed to someone else afterwards.
+    pub async fn release_phone_number(
+        &self,
+        phone_number_sid: &PhoneNumberSid,
+    ) -> Result<(), TwilioError> {
+        let endpoint = format!("IncomingPhoneNumbers/{}", phone_number_sid);
+        self.send_request_no_content(DELETE, &endpoint, NO_PARAMS)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_params() {
+        let settings = PhoneNumberSettings {
+            sms_url: Some("https://example.com/sms"),
+            sms_method: Some(Method::Post),
+            voice_application_sid: Some("APaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".parse().ok()),
+            trunk_sid: Some(None),
+            emergency_status: Some(EmergencyStatus::Active),
+            ..Default::default()
+        };
+        settings.validate().unwrap();
+        let mut params = Vec::new();
+        settings.push_params(&mut params);
+        assert_eq!(
+            params,
+            [
+                ("SmsUrl", "https://example.com/sms".to_string()),
+                (
+                    "VoiceApplicationSid",
+                    "APaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string()
+                ),
+                ("TrunkSid", "".to_string()),
+                ("SmsMethod", "POST".to_string()),
+                ("EmergencyStatus", "Active".to_string()),
+            ]
+        );
+
+        let settings = PhoneNumberSettings {
+            voice_url: Some("https://example.com/voice"),
+            ..settings
+        };
+        assert!(settings.validate().is_err());
+
+        let switch_to_url = PhoneNumberSettings {
+            voice_url: Some("https://example.com/voice"),
+            voice_application_sid: Some(None),
+            ..Default::default()
+        };
+        switch_to_url.validate().unwrap();
+        let mut params = Vec::new();
+        switch_to_url.push_params(&mut params);
+        assert_eq!(
+            params,
+            [
+                ("VoiceUrl", "https://example.com/voice".to_string()),
+                ("VoiceApplicationSid", "".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_phone_number() {
+        let s = r#"{
+            "account_sid": "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "address_requirements": "none",
+            "address_sid": null,
+            "api_version": "2010-04-01",
+            "beta": false,
+            "capabilities": {"voice": true, "sms": false, "mms": true, "fax": false},
+            "date_created": "Thu, 30 Jul 2015 23:19:04 +0000",
+            "date_updated": "Thu, 30 Jul 2015 23:19:04 +0000",
+            "emergency_status": "Active",
+            "emergency_address_sid": "ADaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "friendly_name": "(808) 925-5327",
+            "phone_number": "+18089255327",
+            "origin": "origin",
+            "sid": "PNaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "sms_application_sid": "",
+            "sms_fallback_method": "POST",
+            "sms_fallback_url": "",
+            "sms_method": "POST",
+            "sms_url": "",
+            "status_callback": "",
+            "status_callback_method": "POST",
+            "trunk_sid": null,
+            "voice_application_sid": "",
+            "voice_caller_id_lookup": false,
+            "voice_fallback_method": "POST",
+            "voice_fallback_url": null,
+            "voice_method": "POST",
+            "voice_url": null,
+            "status": "in-use"
+        }"#;
+        let n: IncomingPhoneNumber = serde_json::from_str(s).unwrap();
+        assert_eq!(n.phone_number, "+18089255327");
+        assert!(n.capabilities.voice && n.capabilities.mms && !n.capabilities.sms);
+        assert_eq!(n.voice_method, Some(Method::Post));
+        assert_eq!(n.emergency_status, Some(EmergencyStatus::Active));
+    }
+}
@@ -1,76 +1,419 @@
+use core::fmt;
 use core::num::NonZeroU32;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
 
 use arrayvec::{ArrayString, ArrayVec};
 use bitflags::bitflags;
+use chrono::{DateTime, NaiveDate, Utc};
 use compact_str::CompactString;
-use headers::HeaderMapExt;
-use http_body_util::{BodyExt as _, Either, Empty};
+use futures_core::Stream;
+use futures_util::future::Either;
+use futures_util::stream::{self, StreamExt as _};
 use isocountry::CountryCode;
+use percent_encoding::utf8_percent_encode;
 use serde::de::Error;
 use serde::{Deserialize, Deserializer};
 
-use crate::{Client, TwilioError};
+use crate::{
+    decode_response, Client, Domain, PhoneNumber, TwilioError, VerificationSid, GET, PATH_SEGMENT,
+};
 
 impl Client {
-    pub async fn lookup_phone_number(&self, number: u64) -> Result<PhoneNumberInfo, TwilioError> {
-        // TODO:  Accept Fields as an argument
+    /// Looks up `number`, requesting the data packages in `fields`.  With no fields, only the
+    /// free formatting and validation data is returned.  WhatsApp numbers are looked up by
+    /// their E.164 number; other channel addresses cannot be looked up.
+    pub async fn lookup_phone_number(
+        &self,
+        number: &PhoneNumber,
+        fields: LookupFields,
+    ) -> Result<PhoneNumberInfo, TwilioError> {
+        self.lookup_phone_number_with(number, &LookupOptions::new(fields))
+            .await
+    }
+
+    /// Looks up `number` like [`Client::lookup_phone_number`], with the extra inputs some
+    /// data packages take.
+    pub async fn lookup_phone_number_with(
+        &self,
+        number: &PhoneNumber,
+        options: &LookupOptions<'_>,
+    ) -> Result<PhoneNumberInfo, TwilioError> {
+        let number = number.e164().ok_or(TwilioError::ValidationError(
+            "only phone numbers can be looked up",
+        ))?;
+        self.lookup_phone_number_str_with(number, options).await
+    }
+
+    /// Looks up `number` as typed by a user: either in E.164 format, or in national format
+    /// along with the country it belongs to.
+    pub async fn lookup_phone_number_str(
+        &self,
+        number: &str,
+        country_code: Option<CountryCode>,
+        fields: LookupFields,
+    ) -> Result<PhoneNumberInfo, TwilioError> {
+        let options = LookupOptions {
+            country_code,
+            ..LookupOptions::new(fields)
+        };
+        self.lookup_phone_number_str_with(number, &options).await
+    }
+
+    /// Looks up `number` like [`Client::lookup_phone_number_str`], taking the country from
+    /// [`LookupOptions::country_code`].
+    pub async fn lookup_phone_number_str_with(
+        &self,
+        number: &str,
+        options: &LookupOptions<'_>,
+    ) -> Result<PhoneNumberInfo, TwilioError> {
+        let number = number.trim();
+        if number.is_empty() {
+            return Err(TwilioError::ValidationError("phone number cannot be empty"));
+        }
         let url = format!(
-            "https://lookups.twilio.com/v2/PhoneNumbers/+{number}?Fields=line_type_intelligence",
+            "{}/v2/PhoneNumbers/{}",
+            self.origin(Domain::Lookups),
+            utf8_percent_encode(number, PATH_SEGMENT)
         );
+        let resp = self.execute(GET, &url, &options.params()?).await?;
+        decode_response(resp).await
+    }
 
-        let mut req = hyper::Request::get(url)
-            .body(Either::Left(Empty::new()))
-            .unwrap();
-        req.headers_mut().typed_insert(self.auth_header.clone());
+    /// Looks up many numbers, running up to `batch.concurrency` lookups at once, and streams
+    /// each number back along with its result.
+    ///
+    /// Numbers found in `batch.cache` are not looked up again.  Duplicates that are in flight
+    /// at the same time are both looked up, so deduplicate the input if that matters.
+    pub fn lookup_many<'a, I>(
+        &'a self,
+        numbers: I,
+        options: &'a LookupOptions<'a>,
+        batch: BatchOptions<'a>,
+    ) -> impl Stream<Item = (I::Item, Result<PhoneNumberInfo, TwilioError>)> + 'a
+    where
+        I: IntoIterator,
+        I::IntoIter: 'a,
+        I::Item: AsRef<str> + 'a,
+    {
+        let cache = batch.cache.filter(|_| options.is_cacheable());
+        let lookups = stream::iter(numbers).map(move |number| async move {
+            let key = LookupKey {
+                number: number.as_ref().trim().into(),
+                fields: options.fields,
+                country_code: options.country_code,
+            };
+            if let Some(info) = cache.and_then(|c| c.get(&key)) {
+                return (number, Ok(info));
+            }
+            let result = self
+                .lookup_phone_number_str_with(&key.number, options)
+                .await;
+            if let (Some(c), Ok(info)) = (cache, &result) {
+                c.insert(key, info.clone());
+            }
+            (number, result)
+        });
+        let concurrency = batch.concurrency.max(1);
+        match batch.order {
+            LookupOrder::Input => Either::Left(lookups.buffered(concurrency)),
+            LookupOrder::Completion => Either::Right(lookups.buffer_unordered(concurrency)),
+        }
+    }
+}
 
-        let resp = self
-            .http_client
-            .request(req)
-            .await
-            .map_err(TwilioError::RequestError)?;
+/// The order [`Client::lookup_many`] yields results in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LookupOrder {
+    /// The order the numbers were given in.  A slow lookup holds back the results after it.
+    #[default]
+    Input,
+    /// The order the lookups finish in.
+    Completion,
+}
+
+/// How [`Client::lookup_many`] runs its lookups.
+#[derive(Clone, Copy)]
+pub struct BatchOptions<'a> {
+    /// Maximum number of lookups in flight at once.
+    pub concurrency: usize,
+    pub order: LookupOrder,
+    /// Results to reuse instead of looking numbers up again.  Lookups that check identity
+    /// details or a verification are never cached, since their results depend on more than
+    /// the number.
+    pub cache: Option<&'a dyn LookupCache>,
+}
 
-        let status = resp.status();
-        if !status.is_success() {
-            return Err(TwilioError::HTTPError(status));
+impl Default for BatchOptions<'_> {
+    fn default() -> Self {
+        BatchOptions {
+            concurrency: 10,
+            order: LookupOrder::Input,
+            cache: None,
         }
+    }
+}
 
-        let decoded = resp
-            .into_body()
-            .collect()
-            .await
-            .map_err(TwilioError::ReadResponseError)
-            .and_then(|body| {
-                let bytes = body.to_bytes();
-                serde_json::from_slice(&bytes).map_err(|_| TwilioError::ParsingError)
-            })?;
+impl fmt::Debug for BatchOptions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchOptions")
+            .field("concurrency", &self.concurrency)
+            .field("order", &self.order)
+            .field("cache", &self.cache.is_some())
+            .finish()
+    }
+}
+
+/// What a cached lookup result was requested with.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LookupKey {
+    /// The number as it was passed in, less surrounding whitespace.
+    pub number: CompactString,
+    pub fields: LookupFields,
+    pub country_code: Option<CountryCode>,
+}
+
+/// A store of recent lookup results, so that numbers already looked up are not paid for
+/// again.  It is called from async code, so it should not block for long.
+pub trait LookupCache: Send + Sync {
+    /// Returns the result cached for `key`, if it has not expired.
+    fn get(&self, key: &LookupKey) -> Option<PhoneNumberInfo>;
+
+    fn insert(&self, key: LookupKey, info: PhoneNumberInfo);
+}
+
+/// A [`LookupCache`] that keeps results in memory for a fixed time.
+#[derive(Debug)]
+pub struct MemoryLookupCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<LookupKey, (Instant, PhoneNumberInfo)>>,
+}
+
+impl MemoryLookupCache {
+    /// Creates a cache that keeps each result for `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        MemoryLookupCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Drops every expired result.  Expired results are otherwise only dropped when they are
+    /// looked up again.
+    pub fn purge_expired(&self) {
+        let ttl = self.ttl;
+        self.lock().retain(|_, (at, _)| at.elapsed() < ttl);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<LookupKey, (Instant, PhoneNumberInfo)>> {
+        // The map is always left consistent, so a panic elsewhere does not invalidate it.
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl LookupCache for MemoryLookupCache {
+    fn get(&self, key: &LookupKey) -> Option<PhoneNumberInfo> {
+        let mut entries = self.lock();
+        match entries.get(key) {
+            Some((at, info)) if at.elapsed() < self.ttl => Some(info.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: LookupKey, info: PhoneNumberInfo) {
+        self.lock().insert(key, (Instant::now(), info));
+    }
+}
+
+bitflags! {
+    /// Data packages to request from Lookup.  Each one is billed separately.
+    #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+    pub struct LookupFields: u16 {
+        const LineTypeIntelligence = 0x001;
+        const CallerName = 0x002;
+        const SimSwap = 0x004;
+        const CallForwarding = 0x008;
+        const LineStatus = 0x010;
+        const IdentityMatch = 0x020;
+        const ReassignedNumber = 0x040;
+        const SmsPumpingRisk = 0x080;
+        const PhoneNumberQualityScore = 0x100;
+        const PreFill = 0x200;
+    }
+}
+
+impl LookupFields {
+    fn names(self) -> impl Iterator<Item = &'static str> {
+        IntoIterator::into_iter([
+            (Self::LineTypeIntelligence, "line_type_intelligence"),
+            (Self::CallerName, "caller_name"),
+            (Self::SimSwap, "sim_swap"),
+            (Self::CallForwarding, "call_forwarding"),
+            (Self::LineStatus, "line_status"),
+            (Self::IdentityMatch, "identity_match"),
+            (Self::ReassignedNumber, "reassigned_number"),
+            (Self::SmsPumpingRisk, "sms_pumping_risk"),
+            (Self::PhoneNumberQualityScore, "phone_number_quality_score"),
+            (Self::PreFill, "pre_fill"),
+        ])
+        .filter(move |(flag, _)| self.contains(*flag))
+        .map(|(_, name)| name)
+    }
+}
+
+/// The details of a person to check against the owner of a number with
+/// [`LookupFields::IdentityMatch`].
+#[derive(Debug, Clone, Default)]
+pub struct IdentityQuery<'a> {
+    pub first_name: Option<&'a str>,
+    pub last_name: Option<&'a str>,
+    pub address_line1: Option<&'a str>,
+    pub address_line2: Option<&'a str>,
+    pub city: Option<&'a str>,
+    pub state: Option<&'a str>,
+    pub postal_code: Option<&'a str>,
+    pub address_country_code: Option<CountryCode>,
+    pub national_id: Option<&'a str>,
+    pub date_of_birth: Option<NaiveDate>,
+}
+
+impl IdentityQuery<'_> {
+    fn is_empty(&self) -> bool {
+        self.first_name.is_none()
+            && self.last_name.is_none()
+            && self.address_line1.is_none()
+            && self.address_line2.is_none()
+            && self.city.is_none()
+            && self.state.is_none()
+            && self.postal_code.is_none()
+            && self.address_country_code.is_none()
+            && self.national_id.is_none()
+            && self.date_of_birth.is_none()
+    }
+
+    fn push_params(&self, params: &mut Vec<(&'static str, String)>) {
+        let strings = [
+            ("FirstName", self.first_name),
+            ("LastName", self.last_name),
+            ("AddressLine1", self.address_line1),
+            ("AddressLine2", self.address_line2),
+            ("City", self.city),
+            ("State", self.state),
+            ("PostalCode", self.postal_code),
+            ("NationalId", self.national_id),
+        ];
+        params.extend(
+            strings
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        if let Some(c) = self.address_country_code {
+            params.push(("AddressCountryCode", c.alpha2().to_string()));
+        }
+        if let Some(d) = self.date_of_birth {
+            params.push(("DateOfBirth", d.format("%Y%m%d").to_string()));
+        }
+    }
+}
+
+/// What to look up about a number, and the inputs some data packages need.
+#[derive(Debug, Clone, Default)]
+pub struct LookupOptions<'a> {
+    pub fields: LookupFields,
+    /// Country to interpret a number in national format in.
+    pub country_code: Option<CountryCode>,
+    /// Date the number was last known to belong to the user, for
+    /// [`LookupFields::ReassignedNumber`].
+    pub last_verified_date: Option<NaiveDate>,
+    /// Details to check for [`LookupFields::IdentityMatch`].
+    pub identity: IdentityQuery<'a>,
+    /// SID of the verification that proved the user owns the number, for
+    /// [`LookupFields::PreFill`].
+    pub verification_sid: Option<VerificationSid>,
+}
+
+impl LookupOptions<'_> {
+    pub fn new(fields: LookupFields) -> Self {
+        LookupOptions {
+            fields,
+            ..Default::default()
+        }
+    }
+
+    /// Whether the result depends only on what a [`LookupKey`] holds.
+    fn is_cacheable(&self) -> bool {
+        self.last_verified_date.is_none()
+            && self.identity.is_empty()
+            && self.verification_sid.is_none()
+    }
+
+    fn params(&self) -> Result<Vec<(&'static str, String)>, TwilioError> {
+        if self.last_verified_date.is_some()
+            && !self.fields.contains(LookupFields::ReassignedNumber)
+        {
+            return Err(TwilioError::ValidationError(
+                "LastVerifiedDate requires the reassigned_number field",
+            ));
+        }
+        if !self.identity.is_empty() && !self.fields.contains(LookupFields::IdentityMatch) {
+            return Err(TwilioError::ValidationError(
+                "identity details require the identity_match field",
+            ));
+        }
+        if self.fields.contains(LookupFields::PreFill) && self.verification_sid.is_none() {
+            return Err(TwilioError::ValidationError(
+                "the pre_fill field requires VerificationSid",
+            ));
+        }
 
-        Ok(decoded)
+        let mut params = Vec::new();
+        let fields = self.fields.names().collect::<Vec<_>>();
+        if !fields.is_empty() {
+            params.push(("Fields", fields.join(",")));
+        }
+        if let Some(c) = self.country_code {
+            params.push(("CountryCode", c.alpha2().to_string()));
+        }
+        if let Some(d) = self.last_verified_date {
+            params.push(("LastVerifiedDate", d.format("%Y%m%d").to_string()));
+        }
+        self.identity.push_params(&mut params);
+        if let Some(sid) = self.verification_sid {
+            params.push(("VerificationSid", sid.to_string()));
+        }
+        Ok(params)
     }
 }
 
-#[derive(Debug, Deserialize)]
+/// The result of a lookup.  Each data package is only set when it was requested.
+#[derive(Debug, Clone, Deserialize)]
 pub struct PhoneNumberInfo {
-    // pub call_forwarding: object|null,
-    // pub caller_name: object|null,
+    pub call_forwarding: Option<CallForwarding>,
+    pub caller_name: Option<CallerName>,
     pub calling_country_code: ArrayString<3>,
     pub country_code: CountryCode,
-    // pub identity_match: object|null,
-    // pub line_status: object|null,
+    pub identity_match: Option<IdentityMatch>,
+    pub line_status: Option<LineStatus>,
     pub line_type_intelligence: Option<LineTypeIntelligence>,
     pub national_format: CompactString,
     pub phone_number: CompactString,
-    // pub phone_number_quality_score: object|null,
-    // pub pre_fill: object|null,
-    // pub reassigned_number: object|null,
-    // pub sim_swap: object|null,
-    // pub sms_pumping_risk: object|null,
+    pub phone_number_quality_score: Option<PhoneNumberQualityScore>,
+    pub pre_fill: Option<PreFill>,
+    pub reassigned_number: Option<ReassignedNumber>,
+    pub sim_swap: Option<SimSwap>,
+    pub sms_pumping_risk: Option<SmsPumpingRisk>,
     // pub url: String,
     pub valid: bool,
     #[serde(default)]
     pub validation_errors: ValidationErrors,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct LineTypeIntelligence {
     pub carrier_name: CompactString,
     pub error_code: Option<NonZeroU32>,
@@ -119,6 +462,140 @@ impl<'de> Deserialize<'de> for NumberType {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct CallerName {
+    pub caller_name: Option<CompactString>,
+    pub caller_type: Option<CallerType>,
+    pub error_code: Option<NonZeroU32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CallerType {
+    Business,
+    Consumer,
+    Undetermined,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimSwap {
+    pub last_sim_swap: Option<LastSimSwap>,
+    pub carrier_name: Option<CompactString>,
+    pub mobile_country_code: Option<ArrayString<3>>,
+    pub mobile_network_code: Option<ArrayString<6>>,
+    pub error_code: Option<NonZeroU32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LastSimSwap {
+    /// Only reported for swaps within the last 24 hours.
+    pub last_sim_swap_date: Option<DateTime<Utc>>,
+    /// The window `swapped_in_period` covers, as an ISO 8601 duration such as `PT24H`.
+    pub swapped_period: Option<CompactString>,
+    pub swapped_in_period: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CallForwarding {
+    pub call_forwarding_enabled: Option<bool>,
+    pub error_code: Option<NonZeroU32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LineStatus {
+    pub status: Option<LineState>,
+    pub error_code: Option<NonZeroU32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineState {
+    Active,
+    Inactive,
+    Unreachable,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdentityMatch {
+    pub first_name_match: Option<MatchLevel>,
+    pub last_name_match: Option<MatchLevel>,
+    pub address_lines_match: Option<MatchLevel>,
+    pub city_match: Option<MatchLevel>,
+    pub state_match: Option<MatchLevel>,
+    pub postal_code_match: Option<MatchLevel>,
+    pub address_country_match: Option<MatchLevel>,
+    pub national_id_match: Option<MatchLevel>,
+    pub date_of_birth_match: Option<MatchLevel>,
+    /// Overall confidence that the number belongs to the person, from 0 to 100.
+    pub summary_score: Option<u8>,
+    pub error_code: Option<NonZeroU32>,
+    pub error_message: Option<CompactString>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchLevel {
+    ExactMatch,
+    HighPartialMatch,
+    PartialMatch,
+    NoMatch,
+    NoDataAvailable,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReassignedNumber {
+    pub last_verified_date: Option<NaiveDate>,
+    pub is_number_reassigned: Option<Reassigned>,
+    pub error_code: Option<NonZeroU32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reassigned {
+    Yes,
+    No,
+    NoDataAvailable,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SmsPumpingRisk {
+    pub carrier_risk_category: Option<RiskCategory>,
+    pub number_blocked: Option<bool>,
+    pub number_blocked_date: Option<DateTime<Utc>>,
+    pub number_blocked_last_3_months: Option<bool>,
+    /// Likelihood that messages to the number are SMS pumping fraud, from 0 to 100.
+    pub sms_pumping_risk_score: Option<u8>,
+    pub error_code: Option<NonZeroU32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskCategory {
+    Low,
+    Mild,
+    Moderate,
+    High,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PhoneNumberQualityScore {
+    pub quality_score: Option<u8>,
+    pub error_code: Option<NonZeroU32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PreFill {
+    pub first_name: Option<CompactString>,
+    pub last_name: Option<CompactString>,
+    pub address_line: Option<CompactString>,
+    pub city: Option<CompactString>,
+    pub state: Option<CompactString>,
+    pub postal_code: Option<CompactString>,
+    pub country_code: Option<CompactString>,
+    pub error_code: Option<NonZeroU32>,
+}
+
 bitflags! {
     #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
     pub struct ValidationErrors: u8 {
@@ -158,6 +635,153 @@ impl<'de> Deserialize<'de> for ValidationErrors {
 mod tests {
     use super::*;
 
+    fn info(number: &str) -> PhoneNumberInfo {
+        let s = format!(
+            r#"{{"calling_country_code": "1", "country_code": "US", "phone_number": "{0}",
+                "national_format": "{0}", "valid": true, "validation_errors": []}}"#,
+            number
+        );
+        serde_json::from_str(&s).unwrap()
+    }
+
+    #[test]
+    fn test_memory_cache() {
+        let key = |number: &str| LookupKey {
+            number: number.into(),
+            fields: LookupFields::LineTypeIntelligence,
+            country_code: None,
+        };
+        let cache = MemoryLookupCache::new(Duration::from_secs(60));
+        cache.insert(key("+14155550100"), info("+14155550100"));
+        assert_eq!(
+            cache.get(&key("+14155550100")).unwrap().phone_number,
+            "+14155550100"
+        );
+        assert!(cache.get(&key("+14155550101")).is_none());
+        let other_fields = LookupKey {
+            fields: LookupFields::SimSwap,
+            ..key("+14155550100")
+        };
+        assert!(cache.get(&other_fields).is_none());
+
+        let cache = MemoryLookupCache::new(Duration::ZERO);
+        cache.insert(key("+14155550100"), info("+14155550100"));
+        assert!(cache.get(&key("+14155550100")).is_none());
+        cache.insert(key("+14155550100"), info("+14155550100"));
+        cache.purge_expired();
+        assert!(cache.lock().is_empty());
+    }
+
+    #[test]
+    fn test_lookup_params() {
+        let options = LookupOptions {
+            country_code: Some(CountryCode::USA),
+            last_verified_date: NaiveDate::from_ymd_opt(2023, 1, 31),
+            ..LookupOptions::new(LookupFields::SimSwap | LookupFields::ReassignedNumber)
+        };
+        assert_eq!(
+            options.params().unwrap(),
+            [
+                ("Fields", "sim_swap,reassigned_number".to_string()),
+                ("CountryCode", "US".to_string()),
+                ("LastVerifiedDate", "20230131".to_string()),
+            ]
+        );
+        assert!(LookupOptions::new(LookupFields::empty())
+            .params()
+            .unwrap()
+            .is_empty());
+
+        let options = LookupOptions {
+            identity: IdentityQuery {
+                first_name: Some("Jane"),
+                ..Default::default()
+            },
+            ..LookupOptions::new(LookupFields::LineTypeIntelligence)
+        };
+        assert!(options.params().is_err());
+    }
+
+    #[test]
+    fn test_deserialize_phone_number_info() {
+        let s = r#"{
+            "calling_country_code": "1",
+            "country_code": "US",
+            "phone_number": "+14159929960",
+            "national_format": "(415) 992-9960",
+            "valid": true,
+            "validation_errors": [],
+            "caller_name": {"caller_name": "Sergio Suarez", "caller_type": "CONSUMER", "error_code": null},
+            "sim_swap": {
+                "last_sim_swap": {
+                    "last_sim_swap_date": "2020-04-27T10:18:50Z",
+                    "swapped_period": "PT48H",
+                    "swapped_in_period": true
+                },
+                "carrier_name": "Vodafone UK",
+                "mobile_country_code": "276",
+                "mobile_network_code": "02",
+                "error_code": null
+            },
+            "call_forwarding": {"call_forwarding_enabled": false, "error_code": null},
+            "line_status": {"status": "active", "error_code": null},
+            "line_type_intelligence": null,
+            "identity_match": {
+                "first_name_match": "exact_match",
+                "last_name_match": "high_partial_match",
+                "address_lines_match": "no_match",
+                "city_match": "no_match",
+                "state_match": "high_partial_match",
+                "postal_code_match": "no_data_available",
+                "address_country_match": "exact_match",
+                "national_id_match": "exact_match",
+                "date_of_birth_match": "exact_match",
+                "summary_score": 90,
+                "error_code": null,
+                "error_message": null
+            },
+            "reassigned_number": {
+                "last_verified_date": "2019-09-24",
+                "is_number_reassigned": "no",
+                "error_code": null
+            },
+            "sms_pumping_risk": {
+                "carrier_risk_category": "moderate",
+                "number_blocked": false,
+                "number_blocked_date": null,
+                "number_blocked_last_3_months": null,
+                "sms_pumping_risk_score": 60,
+                "error_code": null
+            },
+            "phone_number_quality_score": null,
+            "pre_fill": null,
+            "url": "https://lookups.twilio.com/v2/PhoneNumbers/+14159929960"
+        }"#;
+        let info: PhoneNumberInfo = serde_json::from_str(s).unwrap();
+        assert_eq!(info.country_code, CountryCode::USA);
+        assert_eq!(
+            info.caller_name.unwrap().caller_type,
+            Some(CallerType::Consumer)
+        );
+        let swap = info.sim_swap.unwrap().last_sim_swap.unwrap();
+        assert_eq!(swap.swapped_in_period, Some(true));
+        assert_eq!(
+            swap.last_sim_swap_date.unwrap().to_rfc3339(),
+            "2020-04-27T10:18:50+00:00"
+        );
+        assert_eq!(info.line_status.unwrap().status, Some(LineState::Active));
+        let identity = info.identity_match.unwrap();
+        assert_eq!(identity.first_name_match, Some(MatchLevel::ExactMatch));
+        assert_eq!(identity.summary_score, Some(90));
+        assert_eq!(
+            info.reassigned_number.unwrap().is_number_reassigned,
+            Some(Reassigned::No)
+        );
+        let risk = info.sms_pumping_risk.unwrap();
+        assert_eq!(risk.carrier_risk_category, Some(RiskCategory::Moderate));
+        assert_eq!(risk.sms_pumping_risk_score, Some(60));
+    }
+
     #[test]
     fn test_deserialize_validation_errors() {
         let s = r#"["TOO_SHORT"]"#;
@@ -0,0 +1,90 @@
+use crate::{
+    de, AccountSid, Client, Domain, Download, ListResource, MediaSid, MessageSid, TwilioError,
+    DELETE, GET, NO_PARAMS,
+};
+use chrono::{DateTime, Utc};
+use futures_core::Stream;
+use serde::Deserialize;
+use tokio::io::AsyncWrite;
+
+/// Metadata of a media file attached to a message.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Media {
+    pub sid: MediaSid,
+    pub account_sid: AccountSid,
+    /// SID of the message the media belongs to.
+    pub parent_sid: MessageSid,
+    pub content_type: String,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_updated: Option<DateTime<Utc>>,
+    pub uri: String,
+}
+
+impl ListResource for Media {
+    const LIST_KEY: &'static str = "media_list";
+}
+
+impl Client {
+    /// Streams the metadata of every media file attached to a message.
+    pub fn list_message_media(
+        &self,
+        msg_sid: &MessageSid,
+    ) -> impl Stream<Item = Result<Media, TwilioError>> + '_ {
+        self.paginate(&format!("Messages/{}/Media", msg_sid), NO_PARAMS)
+    }
+
+    pub async fn fetch_media(
+        &self,
+        msg_sid: &MessageSid,
+        media_sid: &MediaSid,
+    ) -> Result<Media, TwilioError> {
+        let endpoint = format!("Messages/{}/Media/{}", msg_sid, media_sid);
+        self.send_request(GET, &endpoint, NO_PARAMS).await
+    }
+
+    /// Opens the content of a media file.  The returned [`Download`] reports the content type
+    /// and size before any of the body is read.
+    pub async fn open_media(
+        &self,
+        msg_sid: &MessageSid,
+        media_sid: &MediaSid,
+    ) -> Result<Download, TwilioError> {
+        let url = format!(
+            "{}/2010-04-01/Accounts/{}/Messages/{}/Media/{}",
+            self.origin(Domain::Api),
+            self.account_sid,
+            msg_sid,
+            media_sid
+        );
+        self.download(&url).await
+    }
+
+    /// Streams the content of a media file into `writer` and returns the number of bytes
+    /// written.
+    pub async fn download_media<W>(
+        &self,
+        msg_sid: &MessageSid,
+        media_sid: &MediaSid,
+        writer: &mut W,
+    ) -> Result<u64, TwilioError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        self.open_media(msg_sid, media_sid)
+            .await?
+            .write_to(writer)
+            .await
+    }
+
+    pub async fn delete_media(
+        &self,
+        msg_sid: &MessageSid,
+        media_sid: &MediaSid,
+    ) -> Result<(), TwilioError> {
+        let endpoint = format!("Messages/{}/Media/{}", msg_sid, media_sid);
+        self.send_request_no_content(DELETE, &endpoint, NO_PARAMS)
+            .await
+    }
+}
@@ -1,73 +1,912 @@
-use crate::{Client, FromMap, TwilioError, POST};
-use serde::Deserialize;
+use crate::{
+    de, AccountSid, Client, ContentSid, ErrorCode, FromMap, ListResource, MessageSid,
+    MessagingServiceSid, Page, PhoneNumber, TwilioError, DELETE, GET, NO_PARAMS, POST,
+};
+use chrono::{DateTime, Duration, NaiveDate, SecondsFormat, Utc};
+use core::fmt;
+use core::str::FromStr;
+use futures_core::Stream;
+use futures_util::{future, TryStreamExt as _};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 
+/// Maximum number of `MediaUrl`s Twilio accepts on a single message.
+const MAX_MEDIA_URLS: usize = 10;
+/// Maximum length of a message body, in characters.
+const MAX_BODY_CHARS: usize = 1600;
+/// Maximum `ValidityPeriod`, in seconds.
+const MAX_VALIDITY_PERIOD: u32 = 36000;
+/// Earliest a message can be scheduled for, relative to the time it is sent to Twilio.
+const MIN_SCHEDULE_AHEAD: Duration = Duration::minutes(15);
+/// Latest a message can be scheduled for, relative to the time it is sent to Twilio.
+const MAX_SCHEDULE_AHEAD: Duration = Duration::days(35);
+
+/// A message to send through [`Client::send_message`].
+///
+/// [`OutboundMessage::new`] covers a plain SMS; use [`OutboundMessage::builder`] for everything
+/// else.  Either `from` or `messaging_service_sid` must be set, and at least one of `body`,
+/// `media_urls` or `content_sid` must be present.
+#[derive(Debug, Clone)]
 pub struct OutboundMessage<'a> {
-    pub from: &'a str,
-    pub to: &'a str,
-    pub body: &'a str,
+    /// A phone number, short code, alphanumeric sender ID or channel address.
+    pub from: Option<PhoneNumber>,
+    pub to: PhoneNumber,
+    pub body: Option<&'a str>,
+    pub messaging_service_sid: Option<MessagingServiceSid>,
+    pub media_urls: Vec<&'a str>,
+    pub status_callback: Option<&'a str>,
+    /// Seconds the message may sit in Twilio's queue before it is failed, from 1 to 36000.
+    pub validity_period: Option<u32>,
+    pub max_price: Option<f64>,
+    pub provide_feedback: Option<bool>,
+    pub attempt: Option<u32>,
+    pub smart_encoded: Option<bool>,
+    pub shorten_urls: Option<bool>,
+    pub send_as_mms: Option<bool>,
+    pub persistent_action: Vec<&'a str>,
+    pub content_sid: Option<ContentSid>,
+    /// JSON object of values to substitute into the content template.
+    pub content_variables: Option<&'a str>,
+    pub risk_check: Option<RiskCheck>,
+    /// Sends the message at a fixed time instead of immediately.  Must be between 15 minutes
+    /// and 35 days in the future, and requires `messaging_service_sid`.
+    pub schedule_at: Option<DateTime<Utc>>,
 }
 
 impl<'a> OutboundMessage<'a> {
-    pub fn new(from: &'a str, to: &'a str, body: &'a str) -> OutboundMessage<'a> {
-        OutboundMessage { from, to, body }
+    pub fn new(from: PhoneNumber, to: PhoneNumber, body: &'a str) -> OutboundMessage<'a> {
+        Self::builder(to).from(from).body(body).msg
+    }
+
+    pub fn builder(to: PhoneNumber) -> OutboundMessageBuilder<'a> {
+        OutboundMessageBuilder {
+            msg: OutboundMessage {
+                from: None,
+                to,
+                body: None,
+                messaging_service_sid: None,
+                media_urls: Vec::new(),
+                status_callback: None,
+                validity_period: None,
+                max_price: None,
+                provide_feedback: None,
+                attempt: None,
+                smart_encoded: None,
+                shorten_urls: None,
+                send_as_mms: None,
+                persistent_action: Vec::new(),
+                content_sid: None,
+                content_variables: None,
+                risk_check: None,
+                schedule_at: None,
+            },
+        }
+    }
+
+    /// Checks the message against the parameter rules Twilio documents for the Messages
+    /// resource.
+    pub fn validate(&self) -> Result<(), TwilioError> {
+        if !self.to.can_receive() {
+            return Err(TwilioError::ValidationError(
+                "To cannot be a short code or alphanumeric sender ID",
+            ));
+        }
+        if self.from.is_none() && self.messaging_service_sid.is_none() {
+            return Err(TwilioError::ValidationError(
+                "either From or MessagingServiceSid is required",
+            ));
+        }
+        if self.body.is_none() && self.media_urls.is_empty() && self.content_sid.is_none() {
+            return Err(TwilioError::ValidationError(
+                "one of Body, MediaUrl or ContentSid is required",
+            ));
+        }
+        if self.content_sid.is_some() && (self.body.is_some() || !self.media_urls.is_empty()) {
+            return Err(TwilioError::ValidationError(
+                "ContentSid cannot be combined with Body or MediaUrl",
+            ));
+        }
+        if self.content_variables.is_some() && self.content_sid.is_none() {
+            return Err(TwilioError::ValidationError(
+                "ContentVariables requires ContentSid",
+            ));
+        }
+        if self.media_urls.len() > MAX_MEDIA_URLS {
+            return Err(TwilioError::ValidationError(
+                "at most 10 MediaUrls are allowed",
+            ));
+        }
+        if self
+            .body
+            .is_some_and(|b| b.chars().count() > MAX_BODY_CHARS)
+        {
+            return Err(TwilioError::ValidationError(
+                "Body cannot exceed 1600 characters",
+            ));
+        }
+        if self
+            .validity_period
+            .is_some_and(|v| v == 0 || v > MAX_VALIDITY_PERIOD)
+        {
+            return Err(TwilioError::ValidationError(
+                "ValidityPeriod must be between 1 and 36000 seconds",
+            ));
+        }
+        if self.max_price.is_some_and(|p| p.is_nan() || p <= 0.0) {
+            return Err(TwilioError::ValidationError("MaxPrice must be positive"));
+        }
+        if self.shorten_urls == Some(true) && self.messaging_service_sid.is_none() {
+            return Err(TwilioError::ValidationError(
+                "ShortenUrls requires MessagingServiceSid",
+            ));
+        }
+        self.validate_schedule(Utc::now())
+    }
+
+    fn validate_schedule(&self, now: DateTime<Utc>) -> Result<(), TwilioError> {
+        let schedule_at = match self.schedule_at {
+            Some(t) => t,
+            None => return Ok(()),
+        };
+        if self.messaging_service_sid.is_none() {
+            return Err(TwilioError::ValidationError(
+                "ScheduleAt requires MessagingServiceSid",
+            ));
+        }
+        let ahead = schedule_at - now;
+        if ahead < MIN_SCHEDULE_AHEAD || ahead > MAX_SCHEDULE_AHEAD {
+            return Err(TwilioError::ValidationError(
+                "ScheduleAt must be between 15 minutes and 35 days in the future",
+            ));
+        }
+        Ok(())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![("To", self.to.to_string())];
+        if let Some(ref from) = self.from {
+            params.push(("From", from.to_string()));
+        }
+        if let Some(sid) = self.messaging_service_sid {
+            params.push(("MessagingServiceSid", sid.to_string()));
+        }
+        let strings = [
+            ("Body", self.body),
+            ("StatusCallback", self.status_callback),
+        ];
+        params.extend(
+            strings
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        if let Some(sid) = self.content_sid {
+            params.push(("ContentSid", sid.to_string()));
+        }
+        if let Some(v) = self.content_variables {
+            params.push(("ContentVariables", v.to_string()));
+        }
+        params.extend(self.media_urls.iter().map(|u| ("MediaUrl", u.to_string())));
+        params.extend(
+            self.persistent_action
+                .iter()
+                .map(|a| ("PersistentAction", a.to_string())),
+        );
+        let flags = [
+            ("ProvideFeedback", self.provide_feedback),
+            ("SmartEncoded", self.smart_encoded),
+            ("ShortenUrls", self.shorten_urls),
+            ("SendAsMms", self.send_as_mms),
+        ];
+        params.extend(
+            flags
+                .iter()
+                .filter_map(|(k, v)| v.map(|v| (*k, v.to_string()))),
+        );
+        if let Some(v) = self.validity_period {
+            params.push(("ValidityPeriod", v.to_string()));
+        }
+        if let Some(p) = self.max_price {
+            params.push(("MaxPrice", p.to_string()));
+        }
+        if let Some(a) = self.attempt {
+            params.push(("Attempt", a.to_string()));
+        }
+        if let Some(r) = self.risk_check {
+            params.push(("RiskCheck", r.as_ref().to_string()));
+        }
+        if let Some(t) = self.schedule_at {
+            params.push(("ScheduleType", "fixed".to_string()));
+            params.push(("ScheduleAt", t.to_rfc3339_opts(SecondsFormat::Secs, true)));
+        }
+        params
     }
 }
 
-#[derive(Debug, Deserialize)]
-#[allow(non_camel_case_types)]
+/// Builds an [`OutboundMessage`], validating it on [`build`](Self::build).
+#[derive(Debug, Clone)]
+pub struct OutboundMessageBuilder<'a> {
+    msg: OutboundMessage<'a>,
+}
+
+impl<'a> OutboundMessageBuilder<'a> {
+    pub fn from(mut self, from: PhoneNumber) -> Self {
+        self.msg.from = Some(from);
+        self
+    }
+
+    /// Sends the message through a Messaging Service, which picks the sender from its pool
+    /// unless `from` is also set.
+    pub fn messaging_service_sid(mut self, sid: MessagingServiceSid) -> Self {
+        self.msg.messaging_service_sid = Some(sid);
+        self
+    }
+
+    pub fn body(mut self, body: &'a str) -> Self {
+        self.msg.body = Some(body);
+        self
+    }
+
+    /// Attaches a media file, turning the message into an MMS.  May be called up to 10 times.
+    pub fn media_url(mut self, url: &'a str) -> Self {
+        self.msg.media_urls.push(url);
+        self
+    }
+
+    pub fn status_callback(mut self, url: &'a str) -> Self {
+        self.msg.status_callback = Some(url);
+        self
+    }
+
+    pub fn validity_period(mut self, seconds: u32) -> Self {
+        self.msg.validity_period = Some(seconds);
+        self
+    }
+
+    pub fn max_price(mut self, max_price: f64) -> Self {
+        self.msg.max_price = Some(max_price);
+        self
+    }
+
+    pub fn provide_feedback(mut self, provide_feedback: bool) -> Self {
+        self.msg.provide_feedback = Some(provide_feedback);
+        self
+    }
+
+    pub fn attempt(mut self, attempt: u32) -> Self {
+        self.msg.attempt = Some(attempt);
+        self
+    }
+
+    pub fn smart_encoded(mut self, smart_encoded: bool) -> Self {
+        self.msg.smart_encoded = Some(smart_encoded);
+        self
+    }
+
+    pub fn shorten_urls(mut self, shorten_urls: bool) -> Self {
+        self.msg.shorten_urls = Some(shorten_urls);
+        self
+    }
+
+    pub fn send_as_mms(mut self, send_as_mms: bool) -> Self {
+        self.msg.send_as_mms = Some(send_as_mms);
+        self
+    }
+
+    pub fn persistent_action(mut self, action: &'a str) -> Self {
+        self.msg.persistent_action.push(action);
+        self
+    }
+
+    /// Sends a Content API template instead of a body.  `variables`, if given, is a JSON object
+    /// of values for the template's placeholders.
+    pub fn content(mut self, content_sid: ContentSid, variables: Option<&'a str>) -> Self {
+        self.msg.content_sid = Some(content_sid);
+        self.msg.content_variables = variables;
+        self
+    }
+
+    pub fn risk_check(mut self, risk_check: RiskCheck) -> Self {
+        self.msg.risk_check = Some(risk_check);
+        self
+    }
+
+    /// Schedules the message to be sent at `at` rather than immediately.
+    pub fn schedule_at(mut self, at: DateTime<Utc>) -> Self {
+        self.msg.schedule_at = Some(at);
+        self
+    }
+
+    pub fn build(self) -> Result<OutboundMessage<'a>, TwilioError> {
+        self.msg.validate()?;
+        Ok(self.msg)
+    }
+}
+
+/// Whether Twilio's SMS pumping protection screens the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiskCheck {
+    Enable,
+    Disable,
+}
+
+impl AsRef<str> for RiskCheck {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Enable => "enable",
+            Self::Disable => "disable",
+        }
+    }
+}
+
+/// The status of a message, shared by REST responses and webhooks.  Statuses this crate does
+/// not know about yet are preserved in `Unknown` rather than failing to parse.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MessageStatus {
-    queued,
-    sending,
-    sent,
-    failed,
-    delivered,
-    undelivered,
-    receiving,
-    received,
+    Queued,
+    Sending,
+    Sent,
+    Failed,
+    Delivered,
+    Undelivered,
+    Receiving,
+    Received,
+    Accepted,
+    Scheduled,
+    Read,
+    PartiallyDelivered,
+    Canceled,
+    Unknown(String),
+}
+
+impl AsRef<str> for MessageStatus {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Queued => "queued",
+            Self::Sending => "sending",
+            Self::Sent => "sent",
+            Self::Failed => "failed",
+            Self::Delivered => "delivered",
+            Self::Undelivered => "undelivered",
+            Self::Receiving => "receiving",
+            Self::Received => "received",
+            Self::Accepted => "accepted",
+            Self::Scheduled => "scheduled",
+            Self::Read => "read",
+            Self::PartiallyDelivered => "partially_delivered",
+            Self::Canceled => "canceled",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
+impl fmt::Display for MessageStatus {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl MessageStatus {
+    /// Parses a status.  Only input that isn't a non-empty UTF-8 string is rejected; anything
+    /// else that isn't a known status becomes `Unknown`.
+    #[inline]
+    pub fn from_bytes(s: &[u8]) -> Result<Self, InvalidMessageStatus> {
+        let this = match s {
+            b"queued" => Self::Queued,
+            b"sending" => Self::Sending,
+            b"sent" => Self::Sent,
+            b"failed" => Self::Failed,
+            b"delivered" => Self::Delivered,
+            b"undelivered" => Self::Undelivered,
+            b"receiving" => Self::Receiving,
+            b"received" => Self::Received,
+            b"accepted" => Self::Accepted,
+            b"scheduled" => Self::Scheduled,
+            b"read" => Self::Read,
+            b"partially_delivered" => Self::PartiallyDelivered,
+            b"canceled" => Self::Canceled,
+            b"" => return Err(InvalidMessageStatus(String::new())),
+            _ => match core::str::from_utf8(s) {
+                Ok(s) => Self::Unknown(s.to_string()),
+                Err(_) => return Err(InvalidMessageStatus(String::from_utf8_lossy(s).to_string())),
+            },
+        };
+        Ok(this)
+    }
+
+    /// Whether the message will not change status again.
+    #[inline]
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Failed
+                | Self::Delivered
+                | Self::Undelivered
+                | Self::Received
+                | Self::Read
+                | Self::PartiallyDelivered
+                | Self::Canceled
+        )
+    }
+
+    /// Whether the message could not be sent or delivered.
+    #[inline]
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Failed | Self::Undelivered)
+    }
+}
+
+impl FromStr for MessageStatus {
+    type Err = InvalidMessageStatus;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageStatus {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        let s = <Cow<'de, str>>::deserialize(de)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for MessageStatus {
+    #[inline]
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.serialize_str(self.as_ref())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid Twilio message status '{0}'")]
+pub struct InvalidMessageStatus(String);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MessageDirection {
+    Inbound,
+    OutboundApi,
+    OutboundCall,
+    OutboundReply,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct MessageSubresourceUris {
+    pub media: Option<String>,
+    pub feedback: Option<String>,
+}
+
+/// A Message resource, as returned by the REST API or received in a webhook.  Webhooks carry
+/// fewer fields than the REST API, so fields that a webhook may omit are optional.
 #[derive(Debug, Deserialize)]
 pub struct Message {
-    pub from: String,
-    pub to: String,
+    pub account_sid: AccountSid,
+    #[serde(default, deserialize_with = "de::empty_as_none")]
+    pub from: Option<PhoneNumber>,
+    pub to: PhoneNumber,
     pub body: Option<String>,
-    pub sid: String,
+    pub sid: MessageSid,
     pub status: Option<MessageStatus>,
+    pub messaging_service_sid: Option<MessagingServiceSid>,
+    pub direction: Option<MessageDirection>,
+    #[serde(default, deserialize_with = "de::from_str_opt")]
+    pub num_segments: Option<u32>,
+    #[serde(default, deserialize_with = "de::from_str_opt")]
+    pub num_media: Option<u32>,
+    /// The amount billed, as the exact decimal string Twilio reports.  Negative for charges.
+    pub price: Option<String>,
+    /// ISO 4217 currency code of `price`.
+    pub price_unit: Option<String>,
+    pub error_code: Option<ErrorCode>,
+    pub error_message: Option<String>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_sent: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_updated: Option<DateTime<Utc>>,
+    pub api_version: Option<String>,
+    pub subresource_uris: Option<MessageSubresourceUris>,
 }
 
 impl Client {
     pub async fn send_message(&self, msg: OutboundMessage<'_>) -> Result<Message, TwilioError> {
-        let opts = [("To", msg.to), ("From", msg.from), ("Body", msg.body)];
-        self.send_request(POST, "Messages", &opts).await
+        msg.validate()?;
+        self.send_request(POST, "Messages", &msg.params()).await
     }
 
-    pub async fn get_message_status(&self, msg_sid: &str) -> Result<Message, TwilioError> {
-        self.message_status(msg_sid).await
+    pub async fn get_message_status(&self, msg_sid: &MessageSid) -> Result<Message, TwilioError> {
+        self.send_request(GET, &format!("Messages/{}", msg_sid), NO_PARAMS)
+            .await
+    }
+
+    /// Cancels a message that was sent with `schedule_at` and has not gone out yet.
+    pub async fn cancel_scheduled_message(
+        &self,
+        msg_sid: &MessageSid,
+    ) -> Result<Message, TwilioError> {
+        let opts = [("Status", "canceled")];
+        self.send_request(POST, &format!("Messages/{}", msg_sid), &opts)
+            .await
+    }
+
+    /// Permanently deletes a message and its media from Twilio.
+    pub async fn delete_message(&self, msg_sid: &MessageSid) -> Result<(), TwilioError> {
+        self.send_request_no_content(DELETE, &format!("Messages/{}", msg_sid), NO_PARAMS)
+            .await
+    }
+
+    /// Erases the body of a message from Twilio's logs, keeping the rest of its record.
+    pub async fn redact_message(&self, msg_sid: &MessageSid) -> Result<Message, TwilioError> {
+        let opts = [("Body", "")];
+        self.send_request(POST, &format!("Messages/{}", msg_sid), &opts)
+            .await
+    }
+
+    /// Streams the messages matching `filter` that are still waiting to be sent at their
+    /// scheduled time.  Twilio can't filter by status, so every message matching `filter` is
+    /// fetched; narrow it down with a date window, or stop reading once you have what you need.
+    pub fn list_scheduled_messages(
+        &self,
+        filter: &MessageFilter<'_>,
+    ) -> Result<impl Stream<Item = Result<Message, TwilioError>> + '_, TwilioError> {
+        Ok(self
+            .list_messages(filter)?
+            .try_filter(|m| future::ready(m.status == Some(MessageStatus::Scheduled))))
+    }
+
+    /// Streams every message matching `filter`, most recent first, fetching further pages as
+    /// the stream is consumed.
+    pub fn list_messages(
+        &self,
+        filter: &MessageFilter<'_>,
+    ) -> Result<impl Stream<Item = Result<Message, TwilioError>> + '_, TwilioError> {
+        Ok(self.paginate("Messages", &filter.params()?))
+    }
+
+    /// Fetches the first page of messages matching `filter`.  Pass the page's
+    /// `next_page_uri` to [`Client::fetch_page`] to continue.
+    pub async fn list_messages_page(
+        &self,
+        filter: &MessageFilter<'_>,
+    ) -> Result<Page<Message>, TwilioError> {
+        self.first_page("Messages", &filter.params()?).await
+    }
+}
+
+impl ListResource for Message {
+    const LIST_KEY: &'static str = "messages";
+}
+
+/// Narrows down the messages returned by [`Client::list_messages`].
+#[derive(Debug, Clone, Default)]
+pub struct MessageFilter<'a> {
+    pub to: Option<&'a PhoneNumber>,
+    pub from: Option<&'a PhoneNumber>,
+    /// Only messages sent on this day (UTC).
+    pub date_sent: Option<NaiveDate>,
+    /// Only messages sent on or before this time.
+    pub date_sent_before: Option<DateTime<Utc>>,
+    /// Only messages sent on or after this time.
+    pub date_sent_after: Option<DateTime<Utc>>,
+    /// Number of messages per page, from 1 to 1000.
+    pub page_size: Option<u32>,
+}
+
+impl MessageFilter<'_> {
+    fn params(&self) -> Result<Vec<(&'static str, String)>, TwilioError> {
+        let mut params = Vec::new();
+        if let Some(to) = self.to {
+            params.push(("To", to.to_string()));
+        }
+        if let Some(from) = self.from {
+            params.push(("From", from.to_string()));
+        }
+        if let Some(d) = self.date_sent {
+            params.push(("DateSent", d.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(t) = self.date_sent_before {
+            params.push(("DateSent<", t.to_rfc3339_opts(SecondsFormat::Secs, true)));
+        }
+        if let Some(t) = self.date_sent_after {
+            params.push(("DateSent>", t.to_rfc3339_opts(SecondsFormat::Secs, true)));
+        }
+        match self.page_size {
+            Some(size @ 1..=1000) => params.push(("PageSize", size.to_string())),
+            Some(_) => {
+                return Err(TwilioError::ValidationError(
+                    "PageSize must be between 1 and 1000",
+                ))
+            }
+            None => {}
+        }
+        Ok(params)
     }
 }
 
 impl FromMap for Message {
     fn from_map(mut m: BTreeMap<String, String>) -> Result<Box<Message>, TwilioError> {
-        let from = match m.remove("From") {
+        let account_sid = match m.get("AccountSid").and_then(|v| v.parse().ok()) {
             Some(v) => v,
             None => return Err(TwilioError::ParsingError),
         };
-        let to = match m.remove("To") {
-            Some(v) => v,
+        let from = match m.get("From") {
+            Some(v) => PhoneNumber::lenient(v),
+            None => return Err(TwilioError::ParsingError),
+        };
+        let to = match m.get("To") {
+            Some(v) => PhoneNumber::lenient(v),
             None => return Err(TwilioError::ParsingError),
         };
-        let sid = match m.remove("MessageSid") {
+        let sid = match m.get("MessageSid").and_then(|v| v.parse().ok()) {
             Some(v) => v,
             None => return Err(TwilioError::ParsingError),
         };
         let body = m.remove("Body");
+        let status = match m.get("MessageStatus").or_else(|| m.get("SmsStatus")) {
+            Some(v) => Some(v.parse().map_err(|_| TwilioError::ParsingError)?),
+            None => None,
+        };
         Ok(Box::new(Message {
-            from,
+            account_sid,
+            from: Some(from).filter(|f| !f.as_str().is_empty()),
             to,
             sid,
             body,
-            status: None,
+            status,
+            messaging_service_sid: m.get("MessagingServiceSid").and_then(|v| v.parse().ok()),
+            direction: None,
+            num_segments: m.get("NumSegments").and_then(|v| v.parse().ok()),
+            num_media: m.get("NumMedia").and_then(|v| v.parse().ok()),
+            price: None,
+            price_unit: None,
+            error_code: m
+                .get("ErrorCode")
+                .and_then(|v| v.parse::<u32>().ok())
+                .map(ErrorCode::from),
+            error_message: m.remove("ErrorMessage"),
+            date_created: None,
+            date_sent: None,
+            date_updated: None,
+            api_version: m.remove("ApiVersion"),
+            subresource_uris: None,
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(s: &str) -> PhoneNumber {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        let msg = OutboundMessage::builder(number("+15005550001"))
+            .messaging_service_sid("MGaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".parse().unwrap())
+            .media_url("https://example.com/cat.jpg")
+            .build()
+            .unwrap();
+        assert_eq!(msg.from, None);
+        assert_eq!(msg.media_urls, ["https://example.com/cat.jpg"]);
+
+        let no_sender = OutboundMessage::builder(number("+15005550001"))
+            .body("Hi")
+            .build();
+        assert!(matches!(no_sender, Err(TwilioError::ValidationError(_))));
+
+        let no_content = OutboundMessage::builder(number("+15005550001"))
+            .from(number("+15005550006"))
+            .build();
+        assert!(matches!(no_content, Err(TwilioError::ValidationError(_))));
+
+        let content_and_body = OutboundMessage::builder(number("+15005550001"))
+            .from(number("+15005550006"))
+            .body("Hi")
+            .content("HXaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".parse().unwrap(), None)
+            .build();
+        assert!(matches!(
+            content_and_body,
+            Err(TwilioError::ValidationError(_))
+        ));
+
+        let mut too_much_media =
+            OutboundMessage::builder(number("+15005550001")).from(number("+15005550006"));
+        for _ in 0..11 {
+            too_much_media = too_much_media.media_url("https://example.com/cat.jpg");
+        }
+        assert!(matches!(
+            too_much_media.build(),
+            Err(TwilioError::ValidationError(_))
+        ));
+
+        let to_short_code = OutboundMessage::builder(number("12345"))
+            .from(number("+15005550006"))
+            .body("Hi")
+            .build();
+        assert!(matches!(
+            to_short_code,
+            Err(TwilioError::ValidationError(_))
+        ));
+
+        let shorten_without_service = OutboundMessage::builder(number("+15005550001"))
+            .from(number("+15005550006"))
+            .body("https://example.com")
+            .shorten_urls(true)
+            .build();
+        assert!(matches!(
+            shorten_without_service,
+            Err(TwilioError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_schedule_window() {
+        let now = Utc::now();
+        let scheduled = |at| {
+            OutboundMessage::builder(number("+15005550001"))
+                .messaging_service_sid("MGaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".parse().unwrap())
+                .body("Your appointment is tomorrow")
+                .schedule_at(at)
+        };
+
+        let msg = scheduled(now + Duration::hours(1)).msg;
+        assert!(msg.validate_schedule(now).is_ok());
+        let params = msg.params();
+        assert!(params.contains(&("ScheduleType", "fixed".to_string())));
+        assert!(params
+            .iter()
+            .any(|(k, v)| *k == "ScheduleAt" && v.ends_with('Z')));
+
+        let too_soon = scheduled(now + Duration::minutes(5)).msg;
+        assert!(too_soon.validate_schedule(now).is_err());
+        let too_late = scheduled(now + Duration::days(36)).msg;
+        assert!(too_late.validate_schedule(now).is_err());
+
+        let mut no_service = scheduled(now + Duration::hours(1)).msg;
+        no_service.messaging_service_sid = None;
+        no_service.from = Some(number("+15005550006"));
+        assert!(no_service.validate_schedule(now).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_message() {
+        let s = r#"{
+            "account_sid": "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "api_version": "2010-04-01",
+            "body": "Hello",
+            "date_created": "Thu, 24 Aug 2023 05:01:45 +0000",
+            "date_sent": "Thu, 24 Aug 2023 05:01:46 +0000",
+            "date_updated": "Thu, 24 Aug 2023 05:01:47 +0000",
+            "direction": "outbound-api",
+            "error_code": 30007,
+            "error_message": "Message filtered",
+            "from": "+15005550006",
+            "messaging_service_sid": null,
+            "num_media": "0",
+            "num_segments": "2",
+            "price": "-0.01580",
+            "price_unit": "USD",
+            "sid": "SMaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "status": "undelivered",
+            "subresource_uris": {
+                "media": "/2010-04-01/Accounts/ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/Messages/SMaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/Media.json"
+            },
+            "to": "+15005550001",
+            "uri": "/2010-04-01/Accounts/ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/Messages/SMaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa.json"
+        }"#;
+        let msg: Message = serde_json::from_str(s).unwrap();
+        assert_eq!(msg.direction, Some(MessageDirection::OutboundApi));
+        assert_eq!(msg.num_segments, Some(2));
+        assert_eq!(msg.num_media, Some(0));
+        assert_eq!(msg.price.as_deref(), Some("-0.01580"));
+        assert_eq!(msg.error_code, Some(ErrorCode::MessageFiltered));
+        assert_eq!(
+            msg.date_sent.unwrap().to_rfc3339(),
+            "2023-08-24T05:01:46+00:00"
+        );
+        assert!(msg.subresource_uris.unwrap().media.is_some());
+
+        let s = s
+            .replace("+15005550006", "")
+            .replace("+15005550001", "rcs:acme_agent");
+        let msg: Message = serde_json::from_str(&s).unwrap();
+        assert_eq!(msg.from, None);
+        assert_eq!(msg.to.kind(), crate::PhoneNumberKind::Other);
+    }
+
+    #[test]
+    fn test_parse_message_status() {
+        for s in [
+            "accepted",
+            "scheduled",
+            "read",
+            "partially_delivered",
+            "canceled",
+        ] {
+            let status: MessageStatus = serde_json::from_str(&format!("\"{s}\"")).unwrap();
+            assert!(!matches!(status, MessageStatus::Unknown(_)));
+            assert_eq!(status.to_string(), s);
+        }
+
+        let status: MessageStatus = serde_json::from_str("\"teleported\"").unwrap();
+        assert_eq!(status, MessageStatus::Unknown("teleported".to_string()));
+        assert_eq!(serde_json::to_string(&status).unwrap(), "\"teleported\"");
+        assert!(!status.is_terminal());
+
+        assert!(MessageStatus::Undelivered.is_terminal());
+        assert!(MessageStatus::Undelivered.is_failure());
+        assert!(!MessageStatus::Sent.is_terminal());
+        assert!(!MessageStatus::Canceled.is_failure());
+        assert!("".parse::<MessageStatus>().is_err());
+    }
+
+    #[test]
+    fn test_filter_params() {
+        let to = number("+15005550001");
+        let filter = MessageFilter {
+            to: Some(&to),
+            date_sent: NaiveDate::from_ymd_opt(2024, 3, 1),
+            date_sent_after: DateTime::from_timestamp(1709251200, 0),
+            page_size: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.params().unwrap(),
+            [
+                ("To", "+15005550001".to_string()),
+                ("DateSent", "2024-03-01".to_string()),
+                ("DateSent>", "2024-03-01T00:00:00Z".to_string()),
+                ("PageSize", "50".to_string()),
+            ]
+        );
+
+        let filter = MessageFilter {
+            page_size: Some(0),
+            ..Default::default()
+        };
+        assert!(filter.params().is_err());
+    }
+
+    #[test]
+    fn test_params() {
+        let msg = OutboundMessage::builder(number("+15005550001"))
+            .from(number("+15005550006"))
+            .body("Look")
+            .media_url("https://example.com/1.jpg")
+            .media_url("https://example.com/2.jpg")
+            .validity_period(600)
+            .send_as_mms(true)
+            .risk_check(RiskCheck::Disable)
+            .build()
+            .unwrap();
+        let params = msg.params();
+        let get = |key: &str| {
+            params
+                .iter()
+                .filter(|(k, _)| *k == key)
+                .map(|(_, v)| v.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(get("To"), ["+15005550001"]);
+        assert_eq!(get("From"), ["+15005550006"]);
+        assert_eq!(get("Body"), ["Look"]);
+        assert_eq!(
+            get("MediaUrl"),
+            ["https://example.com/1.jpg", "https://example.com/2.jpg"]
+        );
+        assert_eq!(get("ValidityPeriod"), ["600"]);
+        assert_eq!(get("SendAsMms"), ["true"]);
+        assert_eq!(get("RiskCheck"), ["disable"]);
+        assert!(get("MessagingServiceSid").is_empty());
+    }
+}
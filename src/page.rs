@@ -0,0 +1,202 @@
+use crate::{decode_response, url_encode, Client, Domain, TwilioError, GET, NO_PARAMS};
+use futures_core::Stream;
+use futures_util::stream::{self, TryStreamExt as _};
+use serde::de::{DeserializeOwned, Error as _, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A resource that Twilio returns in paginated lists.
+pub trait ListResource: DeserializeOwned {
+    /// Key of the JSON array holding the resources in a page, e.g. `messages`.
+    const LIST_KEY: &'static str;
+}
+
+/// One page of a list of resources.
+///
+/// `next_page_uri` can be stored and later passed to [`Client::fetch_page`] to resume listing
+/// where this page left off.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page: u32,
+    pub page_size: u32,
+    pub next_page_uri: Option<String>,
+}
+
+impl<'de, T: ListResource> Deserialize<'de> for Page<T> {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        struct PageVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: ListResource> Visitor<'de> for PageVisitor<T> {
+            type Value = Page<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a page of {}", T::LIST_KEY)
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Page<T>, A::Error> {
+                let mut items = None;
+                let mut page = 0;
+                let mut page_size = 0;
+                let mut next_page_uri = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        k if k == T::LIST_KEY => items = Some(map.next_value()?),
+                        "page" => page = map.next_value()?,
+                        "page_size" => page_size = map.next_value()?,
+                        "next_page_uri" => next_page_uri = map.next_value()?,
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Page {
+                    items: items.ok_or_else(|| A::Error::missing_field(T::LIST_KEY))?,
+                    page,
+                    page_size,
+                    next_page_uri,
+                })
+            }
+        }
+
+        de.deserialize_map(PageVisitor(PhantomData))
+    }
+}
+
+impl Client {
+    /// Fetches the page at `page_uri`, as found in [`Page::next_page_uri`].
+    ///
+    /// `page_uri` is either a path on the core REST API, or a full URL on one of the client's
+    /// origins.  Anything else is rejected, so that a tampered URI can't send the client's
+    /// credentials to another host.
+    pub async fn fetch_page<T: ListResource>(
+        &self,
+        page_uri: &str,
+    ) -> Result<Page<T>, TwilioError> {
+        let url = self.page_url(page_uri)?;
+        let resp = self.execute(GET, &url, NO_PARAMS).await?;
+        decode_response(resp).await
+    }
+
+    fn page_url(&self, page_uri: &str) -> Result<String, TwilioError> {
+        if page_uri.starts_with('/') {
+            return Ok(format!("{}{}", self.origin(Domain::Api), page_uri));
+        }
+        let on_origin = Domain::ALL.iter().any(|&domain| {
+            page_uri
+                .strip_prefix(self.origin(domain))
+                .is_some_and(|path| path.starts_with('/'))
+        });
+        match on_origin {
+            true => Ok(page_uri.to_string()),
+            false => Err(TwilioError::ValidationError(
+                "page URI must be a path or a URL on one of the client's origins",
+            )),
+        }
+    }
+
+    /// Fetches the first page of an account-scoped list endpoint.
+    pub(crate) async fn first_page<T, V>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, V)],
+    ) -> Result<Page<T>, TwilioError>
+    where
+        T: ListResource,
+        V: AsRef<str>,
+    {
+        self.send_request(GET, endpoint, params).await
+    }
+
+    /// Streams every resource of an account-scoped list endpoint, fetching further pages as
+    /// the stream is consumed.
+    pub(crate) fn paginate<T, V>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, V)],
+    ) -> impl Stream<Item = Result<T, TwilioError>> + '_
+    where
+        T: ListResource + 'static,
+        V: AsRef<str>,
+    {
+        let first = match params.is_empty() {
+            true => self.account_url(endpoint),
+            false => format!("{}?{}", self.account_url(endpoint), url_encode(params)),
+        };
+        stream::try_unfold(Some(first), move |uri| async move {
+            let uri = match uri {
+                Some(uri) => uri,
+                None => return Ok(None),
+            };
+            let page: Page<T> = self.fetch_page(&uri).await?;
+            let items = stream::iter(page.items.into_iter().map(Ok));
+            Ok(Some((items, page.next_page_uri)))
+        })
+        .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Thing {
+        sid: String,
+    }
+
+    impl ListResource for Thing {
+        const LIST_KEY: &'static str = "things";
+    }
+
+    #[test]
+    fn test_deserialize_page() {
+        let s = r#"{
+            "end": 1,
+            "first_page_uri": "/2010-04-01/Accounts/ACXXXX/Things.json?PageSize=2&Page=0",
+            "next_page_uri": "/2010-04-01/Accounts/ACXXXX/Things.json?PageSize=2&Page=1&PageToken=PAXXXX",
+            "page": 0,
+            "page_size": 2,
+            "previous_page_uri": null,
+            "things": [{"sid": "a"}, {"sid": "b"}],
+            "start": 0,
+            "uri": "/2010-04-01/Accounts/ACXXXX/Things.json?PageSize=2&Page=0"
+        }"#;
+        let page: Page<Thing> = serde_json::from_str(s).unwrap();
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[1].sid, "b");
+        assert_eq!(page.page_size, 2);
+        assert!(page.next_page_uri.unwrap().ends_with("PageToken=PAXXXX"));
+
+        let s = r#"{"page": 3, "page_size": 2, "next_page_uri": null}"#;
+        assert!(serde_json::from_str::<Page<Thing>>(s).is_err());
+    }
+
+    #[test]
+    fn test_page_url() {
+        let account_sid = "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".parse().unwrap();
+        let client = Client::builder(account_sid, "token")
+            .domain_base_url(Domain::Lookups, "http://localhost:9000")
+            .build();
+        assert_eq!(
+            client.page_url("/2010-04-01/Things.json?Page=1").unwrap(),
+            "https://api.twilio.com/2010-04-01/Things.json?Page=1"
+        );
+        for url in [
+            "https://api.twilio.com/2010-04-01/Things.json?Page=1",
+            "http://localhost:9000/v2/Things?Page=1",
+        ] {
+            assert_eq!(client.page_url(url).unwrap(), url);
+        }
+        for url in [
+            "https://evil.example.com/2010-04-01/Things.json",
+            "https://api.twilio.com.evil.example.com/Things.json",
+            "https://api.twilio.com@evil.example.com/Things.json",
+            "http://localhost:90001/v2/Things",
+            "Things.json",
+        ] {
+            assert!(client.page_url(url).is_err(), "{}", url);
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use compact_str::CompactString;
+use core::fmt;
+use core::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+/// Fewest digits in an E.164 number, including the country code.
+const MIN_E164_DIGITS: usize = 7;
+/// Most digits in an E.164 number, including the country code.
+const MAX_E164_DIGITS: usize = 15;
+/// Shortest and longest short codes in use across countries.
+const SHORT_CODE_DIGITS: core::ops::RangeInclusive<usize> = 3..=8;
+/// Maximum length of an alphanumeric sender ID.
+const MAX_SENDER_ID_LEN: usize = 11;
+
+const WHATSAPP: &str = "whatsapp:";
+const MESSENGER: &str = "messenger:";
+const CLIENT: &str = "client:";
+const SIP: &str = "sip:";
+const SIM: &str = "sim:";
+const SCHEMES: [&str; 5] = [WHATSAPP, MESSENGER, CLIENT, SIP, SIM];
+
+/// The kind of address held by a [`PhoneNumber`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhoneNumberKind {
+    /// A phone number in E.164 format, e.g. `+15005550006`.
+    E164,
+    /// A short code of 3 to 8 digits.  Only valid as a message sender.
+    ShortCode,
+    /// An alphanumeric sender ID of up to 11 characters, e.g. `Acme`.  Only valid as a message
+    /// sender.  Calls from withheld numbers also report senders such as `Anonymous`.
+    AlphanumericSenderId,
+    /// A WhatsApp user, `whatsapp:` followed by an E.164 number.
+    WhatsApp,
+    /// A Facebook Messenger user or page, `messenger:` followed by its ID.
+    Messenger,
+    /// A Voice SDK client, `client:` followed by its identity.
+    Client,
+    /// A SIP URI, e.g. `sip:alice@example.sip.twilio.com`.
+    Sip,
+    /// A Twilio SIM, `sim:` followed by its SID.  Only seen on calls and messages from SIMs.
+    Sim,
+    /// An address this crate doesn't recognize, such as one on a channel newer than it, or an
+    /// empty one.  Only read from Twilio's responses and webhooks: parsing a string never
+    /// produces it.
+    Other,
+}
+
+/// A phone number, or one of the other addresses Twilio sends to and receives from, checked
+/// when it is parsed rather than when Twilio rejects it.
+///
+/// SIP addresses are always held with their `sip:` scheme, which Twilio leaves out of some
+/// responses.  Addresses in Twilio's responses are not rejected when they fail validation, so
+/// that one unusual record can't break a whole list; they are kept as
+/// [`PhoneNumberKind::Other`] instead.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PhoneNumber(CompactString);
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid phone number '{0}'")]
+pub struct InvalidPhoneNumber(String);
+
+impl PhoneNumber {
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn kind(&self) -> PhoneNumberKind {
+        classify(self.as_str()).unwrap_or(PhoneNumberKind::Other)
+    }
+
+    /// The E.164 number behind a plain or WhatsApp number.
+    pub fn e164(&self) -> Option<&str> {
+        match self.kind() {
+            PhoneNumberKind::E164 => Some(self.as_str()),
+            PhoneNumberKind::WhatsApp => Some(&self.as_str()[WHATSAPP.len()..]),
+            _ => None,
+        }
+    }
+
+    /// Whether Twilio can send messages or place calls to this address.  Short codes and
+    /// sender IDs can only send, and unrecognized addresses are never sent to.
+    pub fn can_receive(&self) -> bool {
+        !matches!(
+            self.kind(),
+            PhoneNumberKind::ShortCode
+                | PhoneNumberKind::AlphanumericSenderId
+                | PhoneNumberKind::Other
+        )
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        if classify(s).is_some() {
+            return Some(Self(CompactString::from(s)));
+        }
+        let has_scheme = s.starts_with('+') || SCHEMES.iter().any(|p| s.starts_with(p));
+        match !has_scheme && s.contains('@') && is_sip_uri(s) {
+            true => Some(Self(compact_str::format_compact!("{}{}", SIP, s))),
+            false => None,
+        }
+    }
+
+    /// Parses an address received from Twilio, keeping it as is if it isn't recognized.
+    pub(crate) fn lenient(s: &str) -> Self {
+        Self::parse(s).unwrap_or_else(|| Self(CompactString::from(s)))
+    }
+}
+
+/// The kind of a valid address, or `None` if it isn't one.
+fn classify(s: &str) -> Option<PhoneNumberKind> {
+    let (kind, valid) = if s.starts_with('+') {
+        (PhoneNumberKind::E164, is_e164(s))
+    } else if let Some(n) = s.strip_prefix(WHATSAPP) {
+        (PhoneNumberKind::WhatsApp, is_e164(n))
+    } else if let Some(id) = s.strip_prefix(MESSENGER) {
+        (
+            PhoneNumberKind::Messenger,
+            !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()),
+        )
+    } else if let Some(identity) = s.strip_prefix(CLIENT) {
+        (PhoneNumberKind::Client, is_token(identity))
+    } else if let Some(uri) = s.strip_prefix(SIP) {
+        (PhoneNumberKind::Sip, is_sip_uri(uri))
+    } else if let Some(sid) = s.strip_prefix(SIM) {
+        (PhoneNumberKind::Sim, is_token(sid))
+    } else if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        (
+            PhoneNumberKind::ShortCode,
+            SHORT_CODE_DIGITS.contains(&s.len()),
+        )
+    } else {
+        (PhoneNumberKind::AlphanumericSenderId, is_sender_id(s))
+    };
+    match valid {
+        true => Some(kind),
+        false => None,
+    }
+}
+
+/// `+` followed by the country code and subscriber number.
+fn is_e164(s: &str) -> bool {
+    match s.strip_prefix('+') {
+        Some(digits) => {
+            (MIN_E164_DIGITS..=MAX_E164_DIGITS).contains(&digits.len())
+                && !digits.starts_with('0')
+                && digits.bytes().all(|b| b.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+fn is_token(s: &str) -> bool {
+    !s.is_empty() && !s.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+/// A SIP URI without its scheme: an optional `user@`, then a host, then any parameters.
+fn is_sip_uri(s: &str) -> bool {
+    if !is_token(s) {
+        return false;
+    }
+    match s.split_once('@') {
+        Some((user, host)) => !user.is_empty() && !host.is_empty() && !host.contains('@'),
+        None => true,
+    }
+}
+
+/// Letters, digits and a few punctuation marks, with at least one letter.
+fn is_sender_id(s: &str) -> bool {
+    (1..=MAX_SENDER_ID_LEN).contains(&s.len())
+        && s.bytes().any(|b| b.is_ascii_alphabetic())
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b' ' | b'+' | b'-' | b'_' | b'&'))
+}
+
+impl FromStr for PhoneNumber {
+    type Err = InvalidPhoneNumber;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| InvalidPhoneNumber(s.to_string()))
+    }
+}
+
+impl TryFrom<&str> for PhoneNumber {
+    type Error = InvalidPhoneNumber;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl TryFrom<String> for PhoneNumber {
+    type Error = InvalidPhoneNumber;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(&s).ok_or(InvalidPhoneNumber(s))
+    }
+}
+
+impl AsRef<str> for PhoneNumber {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for PhoneNumber {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for PhoneNumber {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Ok(Self::lenient(&s))
+    }
+}
+
+impl Serialize for PhoneNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let cases = [
+            ("+15005550006", PhoneNumberKind::E164),
+            ("+2901234", PhoneNumberKind::E164),
+            ("whatsapp:+14155238886", PhoneNumberKind::WhatsApp),
+            ("messenger:1234567890", PhoneNumberKind::Messenger),
+            ("client:alice", PhoneNumberKind::Client),
+            ("sip:alice@example.sip.twilio.com", PhoneNumberKind::Sip),
+            ("sip:example.com;transport=tls", PhoneNumberKind::Sip),
+            (
+                "sim:DEaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                PhoneNumberKind::Sim,
+            ),
+            ("12345", PhoneNumberKind::ShortCode),
+            ("Acme Co", PhoneNumberKind::AlphanumericSenderId),
+            ("Anonymous", PhoneNumberKind::AlphanumericSenderId),
+        ];
+        for (s, kind) in cases {
+            let n: PhoneNumber = s.parse().unwrap();
+            assert_eq!(n.kind(), kind, "{}", s);
+            assert_eq!(n, s);
+        }
+
+        for s in [
+            "",
+            "+1",
+            "+0123456789",
+            "+1 415 555 0100",
+            "+1234567890123456",
+            "15005550006",
+            "whatsapp:15005550006",
+            "messenger:",
+            "client:",
+            "client:alice smith",
+            "sip:",
+            "sip:@example.com",
+            "12",
+            "Acme Corporation",
+            "WhatsApp:+15005550006",
+        ] {
+            assert!(s.parse::<PhoneNumber>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_accessors() {
+        let n: PhoneNumber = "whatsapp:+14155238886".parse().unwrap();
+        assert_eq!(n.e164(), Some("+14155238886"));
+        assert!(n.can_receive());
+        let n: PhoneNumber = "client:alice".parse().unwrap();
+        assert_eq!(n.e164(), None);
+        let n: PhoneNumber = "Acme".parse().unwrap();
+        assert!(!n.can_receive());
+
+        let n: PhoneNumber = "alice@example.com".parse().unwrap();
+        assert_eq!(n.kind(), PhoneNumberKind::Sip);
+        assert_eq!(n.to_string(), "sip:alice@example.com");
+    }
+
+    #[test]
+    fn test_serde() {
+        let n: PhoneNumber = serde_json::from_str("\"+15005550006\"").unwrap();
+        assert_eq!(serde_json::to_string(&n).unwrap(), "\"+15005550006\"");
+        let n: PhoneNumber = serde_json::from_str("\"rcs:acme_agent\"").unwrap();
+        assert_eq!(n.kind(), PhoneNumberKind::Other);
+        assert_eq!(n, "rcs:acme_agent");
+        assert!(!n.can_receive());
+        assert!("rcs:acme_agent".parse::<PhoneNumber>().is_err());
+        let n: PhoneNumber = serde_json::from_str("\"\"").unwrap();
+        assert_eq!(n.kind(), PhoneNumberKind::Other);
+    }
+}
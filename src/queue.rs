@@ -0,0 +1,197 @@
+use crate::twiml::Method;
+use crate::{
+    de, AccountSid, CallSid, Client, ListResource, QueueSid, TwilioError, DELETE, GET, NO_PARAMS,
+    POST,
+};
+use chrono::{DateTime, Utc};
+use futures_core::Stream;
+use serde::Deserialize;
+
+/// Largest `max_size` Twilio allows for a queue.
+const MAX_QUEUE_SIZE: u32 = 5000;
+
+/// A call queue, as filled by TwiML `<Enqueue>`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Queue {
+    pub sid: QueueSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: String,
+    /// Number of calls waiting in the queue.
+    pub current_size: u32,
+    pub max_size: u32,
+    /// Average time in seconds the calls currently in the queue have been waiting.
+    pub average_wait_time: u32,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_updated: Option<DateTime<Utc>>,
+}
+
+impl ListResource for Queue {
+    const LIST_KEY: &'static str = "queues";
+}
+
+/// A call waiting in a queue.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueueMember {
+    pub call_sid: CallSid,
+    pub queue_sid: QueueSid,
+    /// One-based position in the queue.
+    pub position: u32,
+    /// Seconds the call has been waiting.
+    pub wait_time: u32,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_enqueued: Option<DateTime<Utc>>,
+}
+
+impl ListResource for QueueMember {
+    const LIST_KEY: &'static str = "queue_members";
+}
+
+/// Changes to apply to a queue through [`Client::update_queue`].
+#[derive(Debug, Clone, Default)]
+pub struct QueueUpdate<'a> {
+    pub friendly_name: Option<&'a str>,
+    /// Maximum number of calls the queue holds, up to 5000.
+    pub max_size: Option<u32>,
+}
+
+impl QueueUpdate<'_> {
+    fn params(&self) -> Result<Vec<(&'static str, String)>, TwilioError> {
+        let mut params = Vec::new();
+        if let Some(name) = self.friendly_name {
+            params.push(("FriendlyName", name.to_string()));
+        }
+        if let Some(size) = self.max_size {
+            params.push(("MaxSize", max_size(size)?.to_string()));
+        }
+        Ok(params)
+    }
+}
+
+fn max_size(size: u32) -> Result<u32, TwilioError> {
+    match size {
+        1..=MAX_QUEUE_SIZE => Ok(size),
+        _ => Err(TwilioError::ValidationError(
+            "MaxSize must be between 1 and 5000",
+        )),
+    }
+}
+
+fn member_endpoint(queue_sid: &QueueSid, call_sid: Option<&CallSid>) -> String {
+    match call_sid {
+        Some(call_sid) => format!("Queues/{}/Members/{}", queue_sid, call_sid),
+        None => format!("Queues/{}/Members/Front", queue_sid),
+    }
+}
+
+impl Client {
+    /// Creates a queue.  Without a `max_size`, Twilio limits it to 100 calls.
+    pub async fn create_queue(
+        &self,
+        friendly_name: &str,
+        max_size: Option<u32>,
+    ) -> Result<Queue, TwilioError> {
+        let mut params = vec![("FriendlyName", friendly_name.to_string())];
+        if let Some(size) = max_size {
+            params.push(("MaxSize", self::max_size(size)?.to_string()));
+        }
+        self.send_request(POST, "Queues", &params).await
+    }
+
+    /// Streams every queue in the account, including its current size.
+    pub fn list_queues(&self) -> impl Stream<Item = Result<Queue, TwilioError>> + '_ {
+        self.paginate("Queues", NO_PARAMS)
+    }
+
+    pub async fn fetch_queue(&self, queue_sid: &QueueSid) -> Result<Queue, TwilioError> {
+        self.send_request(GET, &format!("Queues/{}", queue_sid), NO_PARAMS)
+            .await
+    }
+
+    pub async fn update_queue(
+        &self,
+        queue_sid: &QueueSid,
+        update: &QueueUpdate<'_>,
+    ) -> Result<Queue, TwilioError> {
+        self.send_request(POST, &format!("Queues/{}", queue_sid), &update.params()?)
+            .await
+    }
+
+    /// Deletes a queue.  Twilio refuses to delete a queue that still has calls in it.
+    pub async fn delete_queue(&self, queue_sid: &QueueSid) -> Result<(), TwilioError> {
+        self.send_request_no_content(DELETE, &format!("Queues/{}", queue_sid), NO_PARAMS)
+            .await
+    }
+
+    /// Streams the calls waiting in a queue, front first.
+    pub fn list_queue_members(
+        &self,
+        queue_sid: &QueueSid,
+    ) -> impl Stream<Item = Result<QueueMember, TwilioError>> + '_ {
+        self.paginate(&format!("Queues/{}/Members", queue_sid), NO_PARAMS)
+    }
+
+    /// Fetches a call waiting in a queue, or the call at the front of the queue if `call_sid`
+    /// is `None`.
+    pub async fn fetch_queue_member(
+        &self,
+        queue_sid: &QueueSid,
+        call_sid: Option<&CallSid>,
+    ) -> Result<QueueMember, TwilioError> {
+        let endpoint = member_endpoint(queue_sid, call_sid);
+        self.send_request(GET, &endpoint, NO_PARAMS).await
+    }
+
+    /// Removes a call from a queue and redirects it to the TwiML at `url`.  If `call_sid` is
+    /// `None`, the call at the front of the queue is dequeued.
+    pub async fn dequeue_member(
+        &self,
+        queue_sid: &QueueSid,
+        call_sid: Option<&CallSid>,
+        url: &str,
+        method: Method,
+    ) -> Result<QueueMember, TwilioError> {
+        let endpoint = member_endpoint(queue_sid, call_sid);
+        let params = [("Url", url), ("Method", method.as_ref())];
+        self.send_request(POST, &endpoint, &params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_update() {
+        let update = QueueUpdate {
+            max_size: Some(250),
+            ..Default::default()
+        };
+        assert_eq!(update.params().unwrap(), [("MaxSize", "250".to_string())]);
+
+        let update = QueueUpdate {
+            max_size: Some(5001),
+            ..Default::default()
+        };
+        assert!(update.params().is_err());
+    }
+
+    #[test]
+    fn test_deserialize_queue() {
+        let s = r#"{
+            "account_sid": "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "average_wait_time": 12,
+            "current_size": 3,
+            "date_created": "Tue, 04 Aug 2015 18:39:09 +0000",
+            "date_updated": "Tue, 04 Aug 2015 18:39:09 +0000",
+            "friendly_name": "0.361280134646222",
+            "max_size": 100,
+            "sid": "QUaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "uri": "/2010-04-01/Accounts/ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa/Queues/QUaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa.json"
+        }"#;
+        let q: Queue = serde_json::from_str(s).unwrap();
+        assert_eq!(q.current_size, 3);
+        assert_eq!(q.average_wait_time, 12);
+    }
+}
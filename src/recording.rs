@@ -0,0 +1,407 @@
+//! Call and conference recordings, and control over recordings of live calls.
+
+use crate::call::CallRecording;
+pub use crate::decrypt::{decrypt, DecryptError, Decryptor};
+use crate::{
+    de, AccountSid, CallSid, Client, ConferenceSid, Domain, Download, ErrorCode, ListResource,
+    Page, PublicKeySid, RecordingSid, TwilioError, DELETE, GET, NO_PARAMS, POST,
+};
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use futures_core::Stream;
+pub use rsa::pkcs1::DecodeRsaPrivateKey;
+pub use rsa::pkcs8::DecodePrivateKey;
+pub use rsa::RsaPrivateKey;
+use serde::{Deserialize, Deserializer};
+use std::convert::TryFrom;
+use tokio::io::{AsyncWrite, AsyncWriteExt as _};
+
+/// Stands in for the SID of the recording currently running on a call.
+const CURRENT: &str = "Twilio.CURRENT";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RecordingStatus {
+    InProgress,
+    Paused,
+    Stopped,
+    Processing,
+    Completed,
+    Absent,
+    Deleted,
+    /// A status this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// How a recording was started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum RecordingSource {
+    DialVerb,
+    Conference,
+    #[serde(rename = "OutboundAPI")]
+    OutboundApi,
+    Trunking,
+    RecordVerb,
+    #[serde(rename = "StartCallRecordingAPI")]
+    StartCallRecordingApi,
+    #[serde(rename = "StartConferenceRecordingAPI")]
+    StartConferenceRecordingApi,
+    /// A source this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// How the audio of an encrypted recording was encrypted.  Pass it to [`decrypt`] or
+/// [`Decryptor::new`] along with the private key to get the audio back.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EncryptionDetails {
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// SID of the public key credential the content encryption key was wrapped with.
+    pub public_key_sid: PublicKeySid,
+    /// Base64 encoded, RSA encrypted content encryption key.
+    pub encrypted_cek: String,
+    /// Base64 encoded AES-GCM initialization vector.
+    pub iv: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Recording {
+    pub sid: RecordingSid,
+    pub account_sid: AccountSid,
+    pub call_sid: Option<CallSid>,
+    pub conference_sid: Option<ConferenceSid>,
+    pub status: RecordingStatus,
+    pub source: Option<RecordingSource>,
+    /// Length of the recording in seconds, unknown until it has been processed.
+    #[serde(default, deserialize_with = "duration")]
+    pub duration: Option<u32>,
+    pub channels: Option<u32>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub start_time: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::rfc2822_opt")]
+    pub date_updated: Option<DateTime<Utc>>,
+    /// The amount billed, as the exact decimal string Twilio reports.  Negative for charges.
+    pub price: Option<String>,
+    /// ISO 4217 currency code of `price`.
+    pub price_unit: Option<String>,
+    pub error_code: Option<ErrorCode>,
+    /// Set when the recording is encrypted with one of the account's public keys.
+    pub encryption_details: Option<EncryptionDetails>,
+    pub api_version: Option<String>,
+}
+
+/// Twilio reports a duration of `-1` until the recording has been processed.
+fn duration<'de, D: Deserializer<'de>>(de: D) -> Result<Option<u32>, D::Error> {
+    let d: Option<i64> = de::from_str_opt(de)?;
+    Ok(d.and_then(|d| u32::try_from(d).ok()))
+}
+
+impl Download {
+    /// Decrypts an encrypted recording as it is streamed into `writer`, and returns the number
+    /// of bytes of audio written.
+    ///
+    /// Audio is written before the recording has been authenticated at the very end, so
+    /// whatever was written must be discarded if this fails.
+    pub async fn decrypt_to<W>(
+        mut self,
+        mut decryptor: Decryptor,
+        writer: &mut W,
+    ) -> Result<u64, TwilioError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut buf = Vec::new();
+        let mut written = 0;
+        while let Some(data) = self.next_chunk().await? {
+            buf.clear();
+            decryptor.update(&data, &mut buf);
+            writer.write_all(&buf).await.map_err(TwilioError::IoError)?;
+            written += buf.len() as u64;
+        }
+        decryptor.finish().map_err(TwilioError::Decryption)?;
+        writer.flush().await.map_err(TwilioError::IoError)?;
+        Ok(written)
+    }
+}
+
+impl ListResource for Recording {
+    const LIST_KEY: &'static str = "recordings";
+}
+
+/// Audio format a recording is downloaded in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFormat {
+    Wav,
+    Mp3,
+}
+
+impl AudioFormat {
+    #[inline]
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Wav => "wav",
+            Self::Mp3 => "mp3",
+        }
+    }
+}
+
+/// What a paused recording contains in place of the paused audio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseBehavior {
+    /// Leave the paused section out of the recording.
+    Skip,
+    /// Replace the paused section with silence.
+    Silence,
+}
+
+impl AsRef<str> for PauseBehavior {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Skip => "skip",
+            Self::Silence => "silence",
+        }
+    }
+}
+
+/// Narrows down the recordings returned by [`Client::list_recordings`].
+#[derive(Debug, Clone, Default)]
+pub struct RecordingFilter {
+    pub call_sid: Option<CallSid>,
+    pub conference_sid: Option<ConferenceSid>,
+    /// Only recordings created on this day (UTC).
+    pub date_created: Option<NaiveDate>,
+    /// Only recordings created on or before this time.
+    pub date_created_before: Option<DateTime<Utc>>,
+    /// Only recordings created on or after this time.
+    pub date_created_after: Option<DateTime<Utc>>,
+    /// Number of recordings per page, from 1 to 1000.
+    pub page_size: Option<u32>,
+}
+
+impl RecordingFilter {
+    fn params(&self) -> Result<Vec<(&'static str, String)>, TwilioError> {
+        let mut params = Vec::new();
+        if let Some(sid) = self.call_sid {
+            params.push(("CallSid", sid.to_string()));
+        }
+        if let Some(sid) = self.conference_sid {
+            params.push(("ConferenceSid", sid.to_string()));
+        }
+        if let Some(d) = self.date_created {
+            params.push(("DateCreated", d.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(t) = self.date_created_before {
+            params.push(("DateCreated<", t.to_rfc3339_opts(SecondsFormat::Secs, true)));
+        }
+        if let Some(t) = self.date_created_after {
+            params.push(("DateCreated>", t.to_rfc3339_opts(SecondsFormat::Secs, true)));
+        }
+        match self.page_size {
+            Some(size @ 1..=1000) => params.push(("PageSize", size.to_string())),
+            Some(_) => {
+                return Err(TwilioError::ValidationError(
+                    "PageSize must be between 1 and 1000",
+                ))
+            }
+            None => {}
+        }
+        Ok(params)
+    }
+}
+
+impl Client {
+    /// Streams every recording in the account matching `filter`, most recent first, fetching
+    /// further pages as the stream is consumed.
+    pub fn list_recordings(
+        &self,
+        filter: &RecordingFilter,
+    ) -> Result<impl Stream<Item = Result<Recording, TwilioError>> + '_, TwilioError> {
+        Ok(self.paginate("Recordings", &filter.params()?))
+    }
+
+    /// Fetches the first page of recordings matching `filter`.  Pass the page's
+    /// `next_page_uri` to [`Client::fetch_page`] to continue.
+    pub async fn list_recordings_page(
+        &self,
+        filter: &RecordingFilter,
+    ) -> Result<Page<Recording>, TwilioError> {
+        self.first_page("Recordings", &filter.params()?).await
+    }
+
+    /// Streams every recording of a call.
+    pub fn list_call_recordings(
+        &self,
+        call_sid: &CallSid,
+    ) -> impl Stream<Item = Result<Recording, TwilioError>> + '_ {
+        self.paginate(&format!("Calls/{}/Recordings", call_sid), NO_PARAMS)
+    }
+
+    pub async fn fetch_recording(
+        &self,
+        recording_sid: &RecordingSid,
+    ) -> Result<Recording, TwilioError> {
+        self.send_request(GET, &format!("Recordings/{}", recording_sid), NO_PARAMS)
+            .await
+    }
+
+    /// Opens the audio of a recording.  Encrypted recordings are returned as stored; decrypt
+    /// them with [`Download::decrypt_to`].
+    pub async fn open_recording(
+        &self,
+        recording_sid: &RecordingSid,
+        format: AudioFormat,
+    ) -> Result<Download, TwilioError> {
+        let url = format!(
+            "{}/2010-04-01/Accounts/{}/Recordings/{}.{}",
+            self.origin(Domain::Api),
+            self.account_sid,
+            recording_sid,
+            format.extension()
+        );
+        self.download(&url).await
+    }
+
+    /// Streams the audio of a recording into `writer` and returns the number of bytes written.
+    pub async fn download_recording<W>(
+        &self,
+        recording_sid: &RecordingSid,
+        format: AudioFormat,
+        writer: &mut W,
+    ) -> Result<u64, TwilioError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        self.open_recording(recording_sid, format)
+            .await?
+            .write_to(writer)
+            .await
+    }
+
+    pub async fn delete_recording(&self, recording_sid: &RecordingSid) -> Result<(), TwilioError> {
+        self.send_request_no_content(DELETE, &format!("Recordings/{}", recording_sid), NO_PARAMS)
+            .await
+    }
+
+    /// Starts recording a call that is in progress.
+    pub async fn start_call_recording(
+        &self,
+        call_sid: &CallSid,
+        recording: &CallRecording<'_>,
+    ) -> Result<Recording, TwilioError> {
+        let mut params = Vec::new();
+        recording.push_params(&mut params);
+        self.send_request(POST, &format!("Calls/{}/Recordings", call_sid), &params)
+            .await
+    }
+
+    /// Pauses a recording of a live call, or the recording currently running on it if
+    /// `recording_sid` is `None`.
+    pub async fn pause_call_recording(
+        &self,
+        call_sid: &CallSid,
+        recording_sid: Option<&RecordingSid>,
+        behavior: Option<PauseBehavior>,
+    ) -> Result<Recording, TwilioError> {
+        let mut params = vec![("Status", "paused")];
+        if let Some(b) = &behavior {
+            params.push(("PauseBehavior", b.as_ref()));
+        }
+        self.update_call_recording(call_sid, recording_sid, &params)
+            .await
+    }
+
+    /// Resumes a paused recording of a live call, or its current recording if
+    /// `recording_sid` is `None`.
+    pub async fn resume_call_recording(
+        &self,
+        call_sid: &CallSid,
+        recording_sid: Option<&RecordingSid>,
+    ) -> Result<Recording, TwilioError> {
+        self.update_call_recording(call_sid, recording_sid, &[("Status", "in-progress")])
+            .await
+    }
+
+    /// Stops a recording of a live call for good, or its current recording if `recording_sid`
+    /// is `None`.
+    pub async fn stop_call_recording(
+        &self,
+        call_sid: &CallSid,
+        recording_sid: Option<&RecordingSid>,
+    ) -> Result<Recording, TwilioError> {
+        self.update_call_recording(call_sid, recording_sid, &[("Status", "stopped")])
+            .await
+    }
+
+    async fn update_call_recording(
+        &self,
+        call_sid: &CallSid,
+        recording_sid: Option<&RecordingSid>,
+        params: &[(&str, &str)],
+    ) -> Result<Recording, TwilioError> {
+        let recording_sid = recording_sid.map_or(CURRENT, |sid| sid.as_str());
+        let endpoint = format!("Calls/{}/Recordings/{}", call_sid, recording_sid);
+        self.send_request(POST, &endpoint, params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_recording() {
+        let s = r#"{
+            "account_sid": "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "api_version": "2010-04-01",
+            "call_sid": "CAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "conference_sid": null,
+            "channels": 2,
+            "date_created": "Fri, 14 Oct 2016 21:56:34 +0000",
+            "date_updated": "Fri, 14 Oct 2016 21:56:38 +0000",
+            "start_time": "Fri, 14 Oct 2016 21:56:34 +0000",
+            "duration": "4",
+            "price": "-0.00250",
+            "price_unit": "USD",
+            "sid": "REaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "source": "StartCallRecordingAPI",
+            "status": "completed",
+            "error_code": null,
+            "encryption_details": {
+                "type": "rsa-aes",
+                "encrypted_cek": "OV4h6zrsxMIW7h0Zfqwfn6TI2GCNl54KALlg8wn5+7BvhVUP26LgUR0URpYHeAUcUzDbNQfy2Ln1y2V0GTNfXa8v1YdhSk7ndB0SxEbYt/2oxj3lM0hfbVdfK6ak/XZVG4lj/MCrU+/pS25b5txzP0gekbIUmkePCu+kWQOlRW5HdyLt9KzTMyK/IRkCXrBl4+v8wTA+4kC0vxMwIODb/aTHe0VEJDX4cqr8DHXc8U1w8Wz+cxtfbzLuEL0/Zh+DqPO4/PD3r1lMTK9RJEfY/N1RLm9xTvF2OoyOPwK7S1NVK2d7KWjM5Uw9DL2q0sL4N5BemHnLvf8GnLZPoiWZ4Pg==",
+                "iv": "AAAAAAAAAAAAAAAA",
+                "public_key_sid": "CRaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+            }
+        }"#;
+        let r: Recording = serde_json::from_str(s).unwrap();
+        assert_eq!(r.status, RecordingStatus::Completed);
+        assert_eq!(r.source, Some(RecordingSource::StartCallRecordingApi));
+        assert_eq!(r.duration, Some(4));
+        assert_eq!(r.channels, Some(2));
+        assert_eq!(r.encryption_details.unwrap().kind, "rsa-aes");
+
+        let s = r#"{
+            "account_sid": "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "sid": "REaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "status": "in-progress",
+            "duration": "-1"
+        }"#;
+        let r: Recording = serde_json::from_str(s).unwrap();
+        assert_eq!(r.status, RecordingStatus::InProgress);
+        assert_eq!(r.duration, None);
+
+        let s = r#"{
+            "account_sid": "ACaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "sid": "REaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "status": "archived",
+            "source": "MediaStreamAPI"
+        }"#;
+        let r: Recording = serde_json::from_str(s).unwrap();
+        assert_eq!(r.status, RecordingStatus::Unknown);
+        assert_eq!(r.source, Some(RecordingSource::Unknown));
+    }
+}
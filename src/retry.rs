@@ -0,0 +1,172 @@
+use crate::TwilioError;
+use hyper::header::RETRY_AFTER;
+use hyper::HeaderMap;
+use std::time::{Duration, SystemTime};
+
+/// Header Twilio uses to deduplicate retried `POST` requests.
+pub(crate) const IDEMPOTENCY_TOKEN: &str = "I-Twilio-Idempotency-Token";
+
+/// Controls how a [`Client`](crate::Client) retries requests that fail with a retryable error
+/// (see [`TwilioError::is_retryable`]).
+///
+/// Delays grow exponentially from `base_delay` and are capped at `max_delay`, with full jitter
+/// applied.  A `Retry-After` header on the response replaces the computed delay; if it asks for
+/// longer than `max_delay`, the error is returned instead.
+///
+/// `POST` requests are only retried when `retry_non_idempotent` is set, in which case every
+/// attempt carries the same `I-Twilio-Idempotency-Token` so that Twilio can discard duplicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first.  `1` disables retries.
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.  This is what a `Client` uses unless told otherwise.
+    pub const fn never() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            retry_non_idempotent: false,
+        }
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Upper bound of the backoff before attempt number `attempt + 1`, without jitter.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |d| d.min(self.max_delay))
+    }
+
+    /// Decides whether to make another attempt after attempt number `attempt` failed with
+    /// `err`, returning how long to wait first.
+    pub(crate) fn next_delay(
+        &self,
+        attempt: u32,
+        err: &TwilioError,
+        retry_after: Option<Duration>,
+        retry_allowed: bool,
+    ) -> Option<Duration> {
+        if !retry_allowed || attempt >= self.max_attempts || !err.is_retryable() {
+            return None;
+        }
+        match retry_after {
+            Some(d) if d > self.max_delay => None,
+            Some(d) => Some(d),
+            None => {
+                let ceiling = self.backoff(attempt).as_millis() as u64;
+                Some(Duration::from_millis(fastrand::u64(0..=ceiling)))
+            }
+        }
+    }
+}
+
+/// Parses a `Retry-After` header, in either its delay-seconds or HTTP-date form.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Generates a fresh value for the `I-Twilio-Idempotency-Token` header.
+pub(crate) fn idempotency_token() -> String {
+    format!("{:032x}", fastrand::u128(..))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::header::HeaderValue;
+    use hyper::StatusCode;
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let policy = RetryPolicy::default()
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(5));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(4), Duration::from_secs(5));
+        assert_eq!(policy.backoff(100), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_next_delay() {
+        let policy = RetryPolicy::default().max_attempts(3);
+        let retryable = TwilioError::HTTPError(StatusCode::SERVICE_UNAVAILABLE);
+        let fatal = TwilioError::HTTPError(StatusCode::BAD_REQUEST);
+
+        assert!(policy.next_delay(1, &retryable, None, true).unwrap() <= policy.base_delay);
+        assert!(policy.next_delay(3, &retryable, None, true).is_none());
+        assert!(policy.next_delay(1, &retryable, None, false).is_none());
+        assert!(policy.next_delay(1, &fatal, None, true).is_none());
+        assert!(RetryPolicy::never()
+            .next_delay(1, &retryable, None, true)
+            .is_none());
+
+        let after = Some(Duration::from_secs(2));
+        assert_eq!(policy.next_delay(1, &retryable, after, true), after);
+        let too_long = Some(policy.max_delay + Duration::from_secs(1));
+        assert!(policy.next_delay(1, &retryable, too_long, true).is_none());
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+    }
+}
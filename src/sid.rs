@@ -0,0 +1,267 @@
+//! Typed Twilio SIDs.
+//!
+//! Every Twilio resource is identified by a 34 character SID: a two letter prefix naming the
+//! kind of resource, followed by 32 hex digits.  [`Sid`] checks both when it is parsed, and
+//! its kind parameter keeps e.g. a [`CallSid`] from being passed where a [`MessageSid`] is
+//! expected.
+
+use arrayvec::ArrayString;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::str::FromStr;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+
+/// Length of every SID, including its prefix.
+const SID_LEN: usize = 34;
+const PREFIX_LEN: usize = 2;
+
+/// A kind of Twilio resource, named by the prefixes of its SIDs.
+pub trait SidKind {
+    /// The prefixes SIDs of this kind may start with.
+    const PREFIXES: &'static [&'static str];
+}
+
+macro_rules! sid_kinds {
+    ($($(#[$doc:meta])* $kind:ident => $alias:ident [$($prefix:literal),+];)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+            pub enum $kind {}
+
+            impl SidKind for $kind {
+                const PREFIXES: &'static [&'static str] = &[$($prefix),+];
+            }
+
+            $(#[$doc])*
+            pub type $alias = Sid<$kind>;
+        )*
+    };
+}
+
+sid_kinds! {
+    /// An account or subaccount.
+    Account => AccountSid ["AC"];
+    /// A Standard or Restricted API key.
+    ApiKey => ApiKeySid ["SK"];
+    /// A message: `SM` for SMS and `MM` for MMS.
+    Message => MessageSid ["SM", "MM"];
+    /// A media file attached to a message.
+    Media => MediaSid ["ME"];
+    /// A Messaging Service.
+    MessagingService => MessagingServiceSid ["MG"];
+    /// A Content API template.
+    Content => ContentSid ["HX"];
+    Call => CallSid ["CA"];
+    Recording => RecordingSid ["RE"];
+    Conference => ConferenceSid ["CF"];
+    Queue => QueueSid ["QU"];
+    /// A TwiML application.
+    Application => ApplicationSid ["AP"];
+    /// A phone number owned by the account.
+    PhoneNumber => PhoneNumberSid ["PN"];
+    /// An Elastic SIP Trunk.
+    Trunk => TrunkSid ["TK"];
+    /// A customer address, as required to buy numbers in some countries.
+    Address => AddressSid ["AD"];
+    /// A regulatory compliance bundle.
+    Bundle => BundleSid ["BU"];
+    /// A public key used to encrypt recordings.
+    PublicKey => PublicKeySid ["CR"];
+    /// A Verify verification.
+    Verification => VerificationSid ["VE"];
+}
+
+/// The SID of a Twilio resource of kind `K`, stored inline without allocating.
+pub struct Sid<K> {
+    sid: ArrayString<SID_LEN>,
+    kind: PhantomData<fn() -> K>,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid SID '{0}'")]
+pub struct InvalidSid(String);
+
+impl<K: SidKind> Sid<K> {
+    fn parse(s: &str) -> Option<Self> {
+        if s.len() != SID_LEN || !K::PREFIXES.iter().any(|p| s.starts_with(p)) {
+            return None;
+        }
+        if !s[PREFIX_LEN..].bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        Some(Sid {
+            sid: ArrayString::from(s).ok()?,
+            kind: PhantomData,
+        })
+    }
+}
+
+impl<K> Sid<K> {
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.sid
+    }
+
+    /// The two letter prefix, e.g. `SM` or `MM` for a [`MessageSid`].
+    #[inline]
+    pub fn prefix(&self) -> &str {
+        &self.sid[..PREFIX_LEN]
+    }
+}
+
+impl<K: SidKind> FromStr for Sid<K> {
+    type Err = InvalidSid;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| InvalidSid(s.to_string()))
+    }
+}
+
+impl<K: SidKind> TryFrom<&str> for Sid<K> {
+    type Error = InvalidSid;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<K> Clone for Sid<K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for Sid<K> {}
+
+impl<K> PartialEq for Sid<K> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.sid == other.sid
+    }
+}
+
+impl<K> Eq for Sid<K> {}
+
+impl<K> PartialEq<str> for Sid<K> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<K> PartialEq<&str> for Sid<K> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<K> PartialOrd for Sid<K> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K> Ord for Sid<K> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sid.cmp(&other.sid)
+    }
+}
+
+impl<K> Hash for Sid<K> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sid.hash(state)
+    }
+}
+
+impl<K> AsRef<str> for Sid<K> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<K> fmt::Debug for Sid<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<K> fmt::Display for Sid<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<K> Serialize for Sid<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct SidVisitor<K>(PhantomData<fn() -> K>);
+
+impl<K: SidKind> Visitor<'_> for SidVisitor<K> {
+    type Value = Sid<K>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a SID starting with {}", K::PREFIXES.join(" or "))
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        Sid::parse(s).ok_or_else(|| E::invalid_value(de::Unexpected::Str(s), &self))
+    }
+}
+
+impl<'de, K: SidKind> Deserialize<'de> for Sid<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(SidVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let sid: MessageSid = "MM0123456789abcdef0123456789abcdef".parse().unwrap();
+        assert_eq!(sid.prefix(), "MM");
+        assert_eq!(sid, "MM0123456789abcdef0123456789abcdef");
+        assert!("SMaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+            .parse::<MessageSid>()
+            .is_ok());
+
+        for s in [
+            "",
+            "CAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "SMaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "SMaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "SMaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaz",
+            "smaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        ] {
+            assert!(s.parse::<MessageSid>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        let sid: CallSid = serde_json::from_str("\"CAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\"").unwrap();
+        assert_eq!(
+            serde_json::to_string(&sid).unwrap(),
+            "\"CAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\""
+        );
+        let err =
+            serde_json::from_str::<CallSid>("\"SMaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\"").unwrap_err();
+        assert!(err.to_string().contains("a SID starting with CA"));
+    }
+}
@@ -1,3 +1,5 @@
+mod dial;
+mod enqueue;
 mod gather;
 mod message;
 mod play;
@@ -6,6 +8,8 @@ mod redirect;
 mod say;
 mod sms;
 
+pub use self::dial::{Conference, Dial, Dialable, Queue};
+pub use self::enqueue::Enqueue;
 pub use self::gather::{Gather, Prompt};
 pub use self::message::Message;
 pub use self::play::{Digits, Play, Playable};
@@ -14,11 +18,13 @@ pub use self::redirect::Redirect;
 pub use self::say::{Say, Voice};
 pub use self::sms::Sms;
 
+use serde::Deserialize;
+
 pub trait Action {
     fn as_twiml(&self) -> String;
 }
 
-#[derive(Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Twiml {
     body: String,
 }
@@ -55,7 +61,20 @@ fn format_xml_string(tag: &str, attributes: &[(&str, &str)], inner: &str) -> Str
     format!("<{}{}>{}</{}>", tag, attribute_str, inner, tag)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum Method {
+    #[serde(rename = "GET", alias = "get")]
     Get,
+    #[serde(rename = "POST", alias = "post")]
     Post,
 }
+
+impl AsRef<str> for Method {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+        }
+    }
+}
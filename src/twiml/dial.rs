@@ -0,0 +1,228 @@
+use super::{format_xml_string, Action, Method};
+use crate::conference::{Beep, ConferenceStatusCallbackEvent};
+use crate::{CallSid, PhoneNumber, PhoneNumberKind};
+
+/// What a `<Dial>` connects the call to.
+pub enum Dialable {
+    /// A phone number.  Voice SDK clients and SIP addresses are dialed with `<Client>` and
+    /// `<Sip>` instead of `<Number>`.
+    Number(PhoneNumber),
+    Conference(Conference),
+    /// Bridges the call with the call at the front of the named queue.
+    Queue(Queue),
+}
+
+pub struct Dial {
+    pub action: Option<String>,
+    pub method: Method,
+    pub timeout_seconds: u32,
+    pub caller_id: Option<PhoneNumber>,
+    pub time_limit_seconds: Option<u32>,
+    pub hangup_on_star: bool,
+    pub dialable: Dialable,
+}
+
+impl Dial {
+    pub fn new(dialable: Dialable) -> Dial {
+        Dial {
+            action: None,
+            method: Method::Post,
+            timeout_seconds: 30,
+            caller_id: None,
+            time_limit_seconds: None,
+            hangup_on_star: false,
+            dialable,
+        }
+    }
+}
+
+impl Action for Dial {
+    fn as_twiml(&self) -> String {
+        let timeout_string = self.timeout_seconds.to_string();
+        let time_limit_string = self.time_limit_seconds.map(|t| t.to_string());
+        let mut attrs = vec![("method", self.method.as_ref())];
+        if let Some(ref a) = self.action {
+            attrs.push(("action", a));
+        }
+        attrs.push(("timeout", &timeout_string));
+        if let Some(ref c) = self.caller_id {
+            attrs.push(("callerId", c.as_str()));
+        }
+        if let Some(ref t) = time_limit_string {
+            attrs.push(("timeLimit", t));
+        }
+        if self.hangup_on_star {
+            attrs.push(("hangupOnStar", "true"));
+        }
+        let inner = match self.dialable {
+            Dialable::Number(ref n) => match n.kind() {
+                PhoneNumberKind::Client => {
+                    format_xml_string("Client", &[], n.as_str().trim_start_matches("client:"))
+                }
+                PhoneNumberKind::Sip => format_xml_string("Sip", &[], n.as_str()),
+                _ => format_xml_string("Number", &[], n.as_str()),
+            },
+            Dialable::Conference(ref c) => c.as_twiml(),
+            Dialable::Queue(ref q) => q.as_twiml(),
+        };
+        format_xml_string("Dial", &attrs, &inner)
+    }
+}
+
+/// Joins the call to a conference room, creating it if needed.  Only valid inside a
+/// [`Dial`].
+#[derive(Default)]
+pub struct Conference {
+    /// Name of the room; calls dialing the same name join the same conference.
+    pub name: String,
+    pub muted: bool,
+    pub beep: Option<Beep>,
+    pub start_conference_on_enter: Option<bool>,
+    pub end_conference_on_exit: bool,
+    /// TwiML or audio to play while waiting for the conference to start.
+    pub wait_url: Option<String>,
+    pub wait_method: Option<Method>,
+    pub max_participants: Option<u32>,
+    pub record_from_start: bool,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<Method>,
+    pub status_callback_event: ConferenceStatusCallbackEvent,
+    /// Call SID of the participant to coach; only that participant hears this call.
+    pub coach: Option<CallSid>,
+    pub participant_label: Option<String>,
+}
+
+impl Conference {
+    fn as_twiml(&self) -> String {
+        let max_string = self.max_participants.map(|m| m.to_string());
+        let events = self
+            .status_callback_event
+            .names()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut attrs = Vec::new();
+        if self.muted {
+            attrs.push(("muted", "true"));
+        }
+        if let Some(ref b) = self.beep {
+            attrs.push(("beep", b.as_ref()));
+        }
+        if let Some(s) = self.start_conference_on_enter {
+            attrs.push(("startConferenceOnEnter", if s { "true" } else { "false" }));
+        }
+        if self.end_conference_on_exit {
+            attrs.push(("endConferenceOnExit", "true"));
+        }
+        if let Some(ref w) = self.wait_url {
+            attrs.push(("waitUrl", w));
+        }
+        if let Some(ref m) = self.wait_method {
+            attrs.push(("waitMethod", m.as_ref()));
+        }
+        if let Some(ref m) = max_string {
+            attrs.push(("maxParticipants", m));
+        }
+        if self.record_from_start {
+            attrs.push(("record", "record-from-start"));
+        }
+        if let Some(ref c) = self.status_callback {
+            attrs.push(("statusCallback", c));
+        }
+        if let Some(ref m) = self.status_callback_method {
+            attrs.push(("statusCallbackMethod", m.as_ref()));
+        }
+        if !events.is_empty() {
+            attrs.push(("statusCallbackEvent", &events));
+        }
+        if let Some(ref c) = self.coach {
+            attrs.push(("coach", c.as_str()));
+        }
+        if let Some(ref l) = self.participant_label {
+            attrs.push(("participantLabel", l));
+        }
+        format_xml_string("Conference", &attrs, &self.name)
+    }
+}
+
+/// Connects the call to the call at the front of a queue.  Only valid inside a [`Dial`].
+pub struct Queue {
+    /// Name of the queue.
+    pub name: String,
+    /// TwiML to run for the dequeued caller before the calls are bridged, e.g. an
+    /// announcement.
+    pub url: Option<String>,
+    pub method: Method,
+}
+
+impl Queue {
+    pub fn new(name: &str) -> Queue {
+        Queue {
+            name: name.to_string(),
+            url: None,
+            method: Method::Post,
+        }
+    }
+
+    fn as_twiml(&self) -> String {
+        let mut attrs = Vec::new();
+        if let Some(ref u) = self.url {
+            attrs.push(("url", u.as_ref()));
+            attrs.push(("method", self.method.as_ref()));
+        }
+        format_xml_string("Queue", &attrs, &self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dial_conference() {
+        let dial = Dial {
+            action: Some("https://example.com/after".to_string()),
+            ..Dial::new(Dialable::Conference(Conference {
+                name: "support-1234".to_string(),
+                beep: Some(Beep::OnEnter),
+                end_conference_on_exit: true,
+                participant_label: Some("customer".to_string()),
+                status_callback_event: ConferenceStatusCallbackEvent::Start
+                    | ConferenceStatusCallbackEvent::End,
+                ..Default::default()
+            }))
+        };
+        assert_eq!(
+            dial.as_twiml(),
+            "<Dial method=\"POST\" action=\"https://example.com/after\" timeout=\"30\">\
+             <Conference beep=\"onEnter\" endConferenceOnExit=\"true\" \
+             statusCallbackEvent=\"start end\" participantLabel=\"customer\">support-1234\
+             </Conference></Dial>"
+        );
+    }
+
+    #[test]
+    fn test_dial_number() {
+        let dial = |n: &str| Dial::new(Dialable::Number(n.parse().unwrap())).as_twiml();
+        assert_eq!(
+            dial("+15005550006"),
+            "<Dial method=\"POST\" timeout=\"30\"><Number>+15005550006</Number></Dial>"
+        );
+        assert_eq!(
+            dial("client:alice"),
+            "<Dial method=\"POST\" timeout=\"30\"><Client>alice</Client></Dial>"
+        );
+        assert_eq!(
+            dial("sip:alice@example.com"),
+            "<Dial method=\"POST\" timeout=\"30\"><Sip>sip:alice@example.com</Sip></Dial>"
+        );
+    }
+
+    #[test]
+    fn test_dial_queue() {
+        let dial = Dial::new(Dialable::Queue(Queue::new("support")));
+        assert_eq!(
+            dial.as_twiml(),
+            "<Dial method=\"POST\" timeout=\"30\"><Queue>support</Queue></Dial>"
+        );
+    }
+}
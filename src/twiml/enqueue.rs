@@ -0,0 +1,57 @@
+use super::{format_xml_string, Action, Method};
+
+/// Puts the call into a queue, creating the queue if needed.
+pub struct Enqueue {
+    /// Name of the queue.
+    pub name: String,
+    /// Where to send the call once it leaves the queue other than by being bridged.
+    pub action: Option<String>,
+    pub method: Method,
+    /// TwiML to run for the caller while they wait, e.g. hold music.
+    pub wait_url: Option<String>,
+    pub wait_url_method: Method,
+}
+
+impl Enqueue {
+    pub fn new(name: &str) -> Enqueue {
+        Enqueue {
+            name: name.to_string(),
+            action: None,
+            method: Method::Post,
+            wait_url: None,
+            wait_url_method: Method::Post,
+        }
+    }
+}
+
+impl Action for Enqueue {
+    fn as_twiml(&self) -> String {
+        let mut attrs = Vec::new();
+        if let Some(ref a) = self.action {
+            attrs.push(("action", a.as_ref()));
+            attrs.push(("method", self.method.as_ref()));
+        }
+        if let Some(ref w) = self.wait_url {
+            attrs.push(("waitUrl", w.as_ref()));
+            attrs.push(("waitUrlMethod", self.wait_url_method.as_ref()));
+        }
+        format_xml_string("Enqueue", &attrs, &self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enqueue() {
+        let enqueue = Enqueue {
+            wait_url: Some("https://example.com/hold".to_string()),
+            ..Enqueue::new("support")
+        };
+        assert_eq!(
+            enqueue.as_twiml(),
+            "<Enqueue waitUrl=\"https://example.com/hold\" waitUrlMethod=\"POST\">support</Enqueue>"
+        );
+    }
+}
@@ -1,12 +1,13 @@
 use super::{format_xml_string, Action, Method};
+use crate::PhoneNumber;
 use std::default::Default;
 
 pub struct Sms {
     pub txt: String,
     pub action: Option<String>,
     pub method: Method,
-    pub from: Option<String>,
-    pub to: Option<String>,
+    pub from: Option<PhoneNumber>,
+    pub to: Option<PhoneNumber>,
     pub status_callback: Option<String>,
 }
 
@@ -22,10 +23,10 @@ impl Action for Sms {
             attrs.push(("action", a));
         }
         if let Some(ref f) = self.from {
-            attrs.push(("from", f));
+            attrs.push(("from", f.as_str()));
         }
         if let Some(ref t) = self.to {
-            attrs.push(("to", t));
+            attrs.push(("to", t.as_str()));
         }
         if let Some(ref c) = self.status_callback {
             attrs.push(("statusCallback", c));
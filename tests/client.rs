@@ -5,14 +5,23 @@ use twilio::{Client, OutboundMessage};
 async fn send_sms() {
     dotenv::dotenv().ok();
 
-    let account_id = env::var("ACCOUNT_ID").expect("Find ACCOUNT_ID environment variable");
+    let account_sid = env::var("ACCOUNT_ID")
+        .expect("Find ACCOUNT_ID environment variable")
+        .parse()
+        .expect("ACCOUNT_ID to be an account SID");
     let auth_token = env::var("AUTH_TOKEN").expect("Find AUTH_TOKEN environment variable");
-    let from = env::var("FROM").expect("Find FROM environment variable");
-    let to = env::var("TO").expect("Find TO environment variable");
+    let from = env::var("FROM")
+        .expect("Find FROM environment variable")
+        .parse()
+        .expect("FROM to be a phone number");
+    let to = env::var("TO")
+        .expect("Find TO environment variable")
+        .parse()
+        .expect("TO to be a phone number");
 
-    let client = Client::new(&account_id, &auth_token);
+    let client = Client::new(account_sid, &auth_token);
     let msg_sid = client
-        .send_message(OutboundMessage::new(&from, &to, "Hello, World!"))
+        .send_message(OutboundMessage::new(from, to, "Hello, World!"))
         .await
         .expect("to send message")
         .sid;
@@ -28,5 +37,5 @@ async fn send_sms() {
 
     println!("STATUS: {:?}", &status);
 
-    assert!(matches!(status, twilio::MessageStatus::sent));
+    assert!(matches!(status, twilio::MessageStatus::Sent));
 }